@@ -1,6 +1,6 @@
 extern crate griddb_rust;
 
-use griddb_rust::get_value;
+use griddb_rust::try_get_value;
 use griddb_rust::griddb::ContainerInfo::*;
 use griddb_rust::griddb::StoreFactory::*;
 use griddb_rust::griddb::Type::*;
@@ -22,7 +22,7 @@ fn main() {
     // get gridstore function
     let store = match factory.get_store(properties) {
         Ok(result) => result,
-        Err(error) => panic!("Error factory get_store() with error code: {:?}", error),
+        Err(error) => panic!("Error factory get_store() with error: {}", error),
     };
 
     let colinfo = ContainerInfo::ContainerInfo(
@@ -37,45 +37,71 @@ fn main() {
         true,
     );
 
-    store.drop_container("col01");
+    if let Err(error) = store.drop_container("col01") {
+        panic!("Error store drop_container() with error: {}", error);
+    }
     let con = match store.put_container(&colinfo, false) {
         Ok(result) => result,
-        Err(error) => panic!("Error store put_container() with error code: {:?}", error),
+        Err(error) => panic!("Error store put_container() with error: {}", error),
     };
-    con.set_auto_commit(false);
-    con.create_index("name", IndexType::Default);
+    if let Err(error) = con.set_auto_commit(false) {
+        panic!("Error container set_auto_commit() with error: {}", error);
+    }
+    if let Err(error) = con.create_index("name", IndexType::Default) {
+        panic!("Error container create_index() with error: {}", error);
+    }
     // Create row for get and set
     let blob1 = vec![65, 66, 67, 68, 69, 70, 71, 72, 73, 74];
     let blob2 = vec![65, 66, 67, 68, 69, 70, 71, 72, 73, 74];
-    con.put(gsvec!["name01".to_string(), false, 100i64, blob1]);
-    con.put(gsvec!["name02".to_string(), false, 100i64, blob2]);
-    con.remove("name02");
-    con.commit();
+    if let Err(error) = con.put(gsvec!["name01".to_string(), false, 100i64, blob1]) {
+        panic!("Error container put() with error: {}", error);
+    }
+    if let Err(error) = con.put(gsvec!["name02".to_string(), false, 100i64, blob2]) {
+        panic!("Error container put() with error: {}", error);
+    }
+    if let Err(error) = con.remove("name02") {
+        panic!("Error container remove() with error: {}", error);
+    }
+    if let Err(error) = con.commit() {
+        panic!("Error container commit() with error: {}", error);
+    }
 
     // container get row
     let _row3 = match con.get("name01") {
         Ok(result) => result,
-        Err(error) => panic!("Error container get row with error code: {:?}", error),
+        Err(error) => panic!("Error container get row with error: {}", error),
     };
 
     // container execute query
     let query = match con.query("select *") {
         Ok(result) => result,
-        Err(error) => panic!("Error container query data with error code: {:?}", error),
+        Err(error) => panic!("Error container query data with error: {}", error),
     };
     let row_set = match query.fetch() {
         Ok(result) => result,
-        Err(error) => panic!("Error query fetch() data with error code: {:?}", error),
+        Err(error) => panic!("Error query fetch() data with error: {}", error),
     };
     while row_set.has_next() {
-        let row_data = match row_set.next() {
+        let row_data = match row_set.next_values() {
+            Ok(result) => result,
+            Err(error) => panic!("Error row set next() row with error: {}", error),
+        };
+        let name: String = match try_get_value![row_data[0]] {
+            Ok(result) => result,
+            Err(error) => panic!("Error try_get_value!() name with error: {}", error),
+        };
+        let active: bool = match try_get_value![row_data[1]] {
+            Ok(result) => result,
+            Err(error) => panic!("Error try_get_value!() active with error: {}", error),
+        };
+        let count: i64 = match try_get_value![row_data[2]] {
+            Ok(result) => result,
+            Err(error) => panic!("Error try_get_value!() count with error: {}", error),
+        };
+        let blob_data: Vec<u8> = match try_get_value![row_data[3]] {
             Ok(result) => result,
-            Err(error) => panic!("Error row set next() row with error code: {:?}", error),
+            Err(error) => panic!("Error try_get_value!() blob_data with error: {}", error),
         };
-        let name: String = get_value![row_data[0]];
-        let active: bool = get_value![row_data[1]];
-        let count: i64 = get_value![row_data[2]];
-        let blob_data: Vec<u8> = get_value![row_data[3]];
         let tup_query = (name, active, count, blob_data);
         println!(
             "Person: name={0} status={1} count={2} lob=[{3}]",
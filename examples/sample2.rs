@@ -1,7 +1,7 @@
 extern crate griddb_rust;
 
 use chrono::Utc;
-use griddb_rust::get_value;
+use griddb_rust::try_get_value;
 use griddb_rust::griddb::ContainerInfo::*;
 use griddb_rust::griddb::StoreFactory::*;
 use griddb_rust::griddb::Type::*;
@@ -23,7 +23,7 @@ fn main() {
     // get gridstore function
     let store = match factory.get_store(properties) {
         Ok(result) => result,
-        Err(error) => panic!("Error factory get_store() with error code: {:?}", error),
+        Err(error) => panic!("Error factory get_store() with error: {}", error),
     };
 
     let colinfo = ContainerInfo::ContainerInfo(
@@ -38,31 +38,42 @@ fn main() {
     );
     let con = match store.put_container(&colinfo, false) {
         Ok(result) => result,
-        Err(error) => panic!("Error store put_container() with error code: {:?}", error),
+        Err(error) => panic!("Error store put_container() with error: {}", error),
     };
     let timestamp: Timestamp = Timestamp {
         value: Utc::now().timestamp_millis(),
     };
-    con.put(gsvec![timestamp, false, 100.0f64]);
+    if let Err(error) = con.put(gsvec![timestamp, false, 100.0f64]) {
+        panic!("Error container put() with error: {}", error);
+    }
 
     // container execute query
     let query = match con.query("select * where timestamp > TIMESTAMPADD(HOUR, NOW(), -6)") {
         Ok(result) => result,
-        Err(error) => panic!("Error container query data with error code: {:?}", error),
+        Err(error) => panic!("Error container query data with error: {}", error),
     };
     let row_set = match query.fetch() {
         Ok(result) => result,
-        Err(error) => panic!("Error query fetch() data with error code: {:?}", error),
+        Err(error) => panic!("Error query fetch() data with error: {}", error),
     };
-    while row_set.has_next() {
-        let row_data = match row_set.next() {
+    for row in row_set {
+        let row = match row {
+            Ok(result) => result,
+            Err(error) => panic!("Error row set next() row with error: {}", error),
+        };
+        let timestamp: Timestamp = match try_get_value![row["timestamp"]] {
             Ok(result) => result,
-            Err(error) => panic!("Error row set next() row with error code: {:?}", error),
+            Err(error) => panic!("Error try_get_value!() timestamp with error: {}", error),
         };
-        let timestamp: Timestamp = get_value![row_data[0]];
         let timestamp_number: i64 = timestamp.value;
-        let active: bool = get_value![row_data[1]];
-        let vol: f64 = get_value![row_data[2]];
+        let active: bool = match try_get_value![row["active"]] {
+            Ok(result) => result,
+            Err(error) => panic!("Error try_get_value!() active with error: {}", error),
+        };
+        let vol: f64 = match row.get("voltage") {
+            Ok(result) => result,
+            Err(error) => panic!("Error row get() with error: {}", error),
+        };
         println!(
             "Time = {:?} Active = {:?} Voltage = {:.2}",
             timestamp_number, active, vol
@@ -1,8 +1,9 @@
 extern crate griddb_rust;
 
-use griddb_rust::get_value;
 use griddb_rust::griddb::StoreFactory::*;
+use griddb_rust::griddb::Type::*;
 use griddb_rust::griddb::Value::*;
+use griddb_rust::try_get_value;
 use std::env;
 
 fn main() {
@@ -19,56 +20,52 @@ fn main() {
     // get gridstore function
     let store = match factory.get_store(properties) {
         Ok(result) => result,
-        Err(error) => panic!("Error factory get_store() with error code: {:?}", error),
+        Err(error) => panic!("Error factory get_store() with error: {}", error),
     };
-    let con = match store.get_container("point01") {
+    let ts = match store.get_time_series("point01") {
         Ok(result) => result,
-        Err(error) => panic!("Error store put_container() with error code: {:?}", error),
+        Err(error) => panic!("Error store get_time_series() with error: {}", error),
     };
     // container execute query
-    let query = match con.query("select * from point01 where not active and voltage > 50") {
+    let query = match ts
+        .container()
+        .query("select * from point01 where not active and voltage > 50")
+    {
         Ok(result) => result,
-        Err(error) => panic!("Error container query data with error code: {:?}", error),
+        Err(error) => panic!("Error container query data with error: {}", error),
     };
     let row_set = match query.fetch() {
         Ok(result) => result,
-        Err(error) => panic!("Error query fetch() data with error code: {:?}", error),
+        Err(error) => panic!("Error query fetch() data with error: {}", error),
     };
-    let mut agg_query;
-    let mut ts;
+    // 10 minutes before and after each timestamp
+    let window = 10 * 60 * 1000;
     while row_set.has_next() {
-        let row = match row_set.next() {
+        let row = match row_set.next_values() {
             Ok(result) => result,
-            Err(error) => panic!("Error row set next() row with error code: {}", error),
+            Err(error) => panic!("Error row set next() row with error: {}", error),
         };
-        let timestamp: Timestamp = get_value![row[0]];
-        ts = timestamp.value;
-        let average_query = format!("select AVG(voltage) from point01 where timestamp > TIMESTAMPADD(MINUTE, TO_TIMESTAMP_MS({ts}), -10) AND timestamp < TIMESTAMPADD(MINUTE, TO_TIMESTAMP_MS({ts}), 10)");
-        agg_query = match con.query(&average_query[..]) {
+        let timestamp: Timestamp = match try_get_value![row[0]] {
             Ok(result) => result,
-            Err(error) => panic!(
-                "Error container query aggregation data with error code: {}",
-                error
-            ),
+            Err(error) => panic!("Error try_get_value!() timestamp with error: {}", error),
         };
-        let agg_result = match agg_query.fetch() {
-            Ok(result) => result,
-            Err(error) => panic!(
-                "Error query fetch() aggregation data with error code: {}",
-                error
-            ),
+        let start = Timestamp {
+            value: timestamp.value - window,
         };
-        let agg_data = match agg_result.next_aggregation() {
-            Ok(result) => result,
-            Err(error) => panic!(
-                "Error row set next() aggregation row with error code: {}",
-                error
-            ),
+        let end = Timestamp {
+            value: timestamp.value + window,
+        };
+        let agg_data = match ts.aggregate(start, end, "voltage", Aggregation::Average) {
+            Ok(Some(result)) => result,
+            Ok(None) => continue,
+            Err(error) => panic!("Error time series aggregate() with error: {}", error),
         };
         println!(
             "[Timestamp = {:?}] Average voltage = {:.2}",
-            ts,
-            agg_data.get_as_f64().1
+            timestamp.value,
+            agg_data
+                .get_as_f64()
+                .expect("Error aggregation result get_as_f64()")
         );
     }
 }
@@ -0,0 +1,78 @@
+extern crate griddb_rust;
+
+use griddb_rust::griddb::GridRow::*;
+use griddb_rust::griddb::StoreFactory::*;
+use griddb_rust::griddb::Type::*;
+use std::env;
+
+#[derive(GridRow, Clone, Debug)]
+struct Person {
+    #[griddb(key)]
+    name: String,
+    status: bool,
+    #[griddb(rename = "count")]
+    visit_count: i64,
+    #[griddb(nullable)]
+    lob: Option<Vec<u8>>,
+    comment: Option<String>,
+}
+
+fn main() {
+    // get default factory
+    let factory = StoreFactory::get_instance();
+    let args: Vec<_> = env::args().collect();
+    let properties = vec![
+        ("notification_address", args[1].as_str()),
+        ("notification_port", args[2].as_str()),
+        ("cluster_name", args[3].as_str()),
+        ("user", args[4].as_str()),
+        ("password", args[5].as_str()),
+    ];
+    // get gridstore function
+    let store = match factory.get_store(properties) {
+        Ok(result) => result,
+        Err(error) => panic!("Error factory get_store() with error: {}", error),
+    };
+
+    // Schema is generated from struct Person
+    if let Err(error) = store.drop_container("col04") {
+        panic!("Error store drop_container() with error: {}", error);
+    }
+    let con = match store.put_typed_container::<Person>("col04", ContainerType::Collection, false) {
+        Ok(result) => result,
+        Err(error) => panic!("Error store put_typed_container() with error: {}", error),
+    };
+    let person = Person {
+        name: "name01".to_string(),
+        status: false,
+        visit_count: 100,
+        lob: Some(vec![65, 66, 67, 68, 69, 70, 71, 72, 73, 74]),
+        comment: None,
+    };
+    if let Err(error) = con.put(&person) {
+        panic!("Error container put() with error: {}", error);
+    }
+
+    // container get row
+    match con.get(&"name01".to_string()) {
+        Ok(Some(person)) => println!("{:?}", person),
+        Ok(None) => println!("Row name01 does not exist"),
+        Err(error) => panic!("Error container get row with error: {}", error),
+    };
+
+    // query rows as Person
+    let query = match con.query("select * where count >= 100") {
+        Ok(result) => result,
+        Err(error) => panic!("Error container query data with error: {}", error),
+    };
+    let rs = match query.fetch() {
+        Ok(result) => result,
+        Err(error) => panic!("Error query fetch() data with error: {}", error),
+    };
+    for person in rs {
+        match person {
+            Ok(person) => println!("{:?}", person),
+            Err(error) => panic!("Error row set next() with error: {}", error),
+        }
+    }
+}
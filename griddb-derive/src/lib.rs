@@ -0,0 +1,324 @@
+/*
+    Copyright (c) 2022 TOSHIBA Digital Solutions Corporation.
+
+    Licensed under the Apache License, Version 2.0 (the "License");
+    you may not use this file except in compliance with the License.
+    You may obtain a copy of the License at
+
+        http://www.apache.org/licenses/LICENSE-2.0
+
+    Unless required by applicable law or agreed to in writing, software
+    distributed under the License is distributed on an "AS IS" BASIS,
+    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+    See the License for the specific language governing permissions and
+    limitations under the License.
+*/
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, LitStr};
+
+// Column settings read from #[griddb(...)]
+struct ColumnAttr {
+    key: bool,
+    nullable: bool,
+    rename: Option<String>,
+}
+
+fn parse_column_attr(field: &syn::Field) -> syn::Result<ColumnAttr> {
+    let mut column = ColumnAttr {
+        key: false,
+        nullable: false,
+        rename: None,
+    };
+    for attr in &field.attrs {
+        if !attr.path().is_ident("griddb") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("key") {
+                column.key = true;
+                Ok(())
+            } else if meta.path.is_ident("nullable") {
+                column.nullable = true;
+                Ok(())
+            } else if meta.path.is_ident("rename") {
+                let name: LitStr = meta.value()?.parse()?;
+                column.rename = Some(name.value());
+                Ok(())
+            } else {
+                Err(meta.error("expected `key`, `nullable` or `rename = \"...\"`"))
+            }
+        })?;
+    }
+    Ok(column)
+}
+
+// Option<T>, std::option::Option<T> and so on
+fn is_option(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(type_path) if type_path.qself.is_none() => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Option"),
+        _ => false,
+    }
+}
+
+// Implement griddb_rust::griddb::GridRow::GridRow for a struct with named fields
+#[proc_macro_derive(GridRow, attributes(griddb))]
+pub fn derive_grid_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_grid_row(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn expand_grid_row(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    input,
+                    "GridRow can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                input,
+                "GridRow can only be derived for structs",
+            ))
+        }
+    };
+    if fields.is_empty() {
+        return Err(syn::Error::new_spanned(
+            input,
+            "GridRow needs at least one field",
+        ));
+    }
+
+    let mut row_key = false;
+    let mut key_type = quote! { () };
+    let mut columns = Vec::new();
+    let mut to_values = Vec::new();
+    let mut from_values = Vec::new();
+    for (pos, field) in fields.iter().enumerate() {
+        let column = parse_column_attr(field)?;
+        let ident = field.ident.as_ref().unwrap();
+        let ty = &field.ty;
+        let name = column
+            .rename
+            .unwrap_or_else(|| ident.to_string().trim_start_matches("r#").to_string());
+        if column.key {
+            // GridDB uses the first column as row key
+            if pos != 0 {
+                return Err(syn::Error::new_spanned(
+                    field,
+                    "#[griddb(key)] must be on the first field",
+                ));
+            }
+            if column.nullable || is_option(ty) {
+                return Err(syn::Error::new_spanned(
+                    field,
+                    "#[griddb(key)] column cannot be nullable",
+                ));
+            }
+            row_key = true;
+            key_type = quote! { #ty };
+        }
+        // from_row() cannot put NULL into a field that is not an Option
+        if column.nullable && !is_option(ty) {
+            return Err(syn::Error::new_spanned(
+                ty,
+                "#[griddb(nullable)] needs an Option<T> field",
+            ));
+        }
+        let nullable = column.nullable;
+        columns.push(quote! {
+            (
+                #name,
+                <#ty as ::griddb_rust::griddb::GridRow::GridColumn>::TYPE,
+                if #nullable || <#ty as ::griddb_rust::griddb::GridRow::GridColumn>::NULLABLE {
+                    ::griddb_rust::griddb::Type::TypeOption::Nullable
+                } else {
+                    ::griddb_rust::griddb::Type::TypeOption::NotNull
+                },
+            )
+        });
+        to_values.push(quote! {
+            ::griddb_rust::griddb::Value::Value::from(::std::clone::Clone::clone(&self.#ident))
+        });
+        from_values.push(quote! {
+            #ident: ::griddb_rust::griddb::GridRow::column_value(values.next().unwrap(), #name)?
+        });
+    }
+    let column_count = fields.len();
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::griddb_rust::griddb::GridRow::GridRow for #ident #ty_generics #where_clause {
+            const ROW_KEY: bool = #row_key;
+
+            type Key = #key_type;
+
+            fn columns() -> ::std::vec::Vec<(
+                &'static str,
+                ::griddb_rust::griddb::Type::Type,
+                ::griddb_rust::griddb::Type::TypeOption,
+            )> {
+                ::std::vec![#(#columns),*]
+            }
+
+            fn to_row(&self) -> ::std::vec::Vec<::griddb_rust::griddb::Value::Value> {
+                ::std::vec![#(#to_values),*]
+            }
+
+            fn from_row(
+                row: ::std::vec::Vec<::griddb_rust::griddb::Value::Value>,
+            ) -> ::std::result::Result<Self, ::griddb_rust::griddb::Error::GridDbError> {
+                ::griddb_rust::griddb::GridRow::check_column_count(&row, #column_count)?;
+                let mut values = row.into_iter();
+                ::std::result::Result::Ok(#ident {
+                    #(#from_values),*
+                })
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    fn expand_error(input: DeriveInput) -> String {
+        match expand_grid_row(&input) {
+            Ok(_) => panic!("expansion should fail"),
+            Err(error) => error.to_string(),
+        }
+    }
+
+    #[test]
+    fn expand_columns_and_row_key() {
+        let input: DeriveInput = parse_quote! {
+            struct Person {
+                #[griddb(key)]
+                name: String,
+                #[griddb(rename = "count")]
+                visit_count: i64,
+                #[griddb(nullable)]
+                comment: Option<String>,
+            }
+        };
+        let tokens = expand_grid_row(&input).unwrap().to_string();
+        assert!(tokens.contains("const ROW_KEY : bool = true"));
+        assert!(tokens.contains("type Key = String"));
+        assert!(tokens.contains("\"name\""));
+        assert!(tokens.contains("\"count\""));
+        assert!(!tokens.contains("\"visit_count\""));
+        assert!(tokens.contains("\"comment\""));
+        assert!(tokens.contains("check_column_count (& row , 3usize)"));
+    }
+
+    #[test]
+    fn expand_without_row_key() {
+        let input: DeriveInput = parse_quote! {
+            struct Point {
+                r#type: i32,
+                value: f64,
+            }
+        };
+        let tokens = expand_grid_row(&input).unwrap().to_string();
+        assert!(tokens.contains("const ROW_KEY : bool = false"));
+        assert!(tokens.contains("type Key = ()"));
+        assert!(tokens.contains("\"type\""));
+    }
+
+    #[test]
+    fn nullable_needs_option() {
+        let error = expand_error(parse_quote! {
+            struct Person {
+                #[griddb(nullable)]
+                lob: Vec<u8>,
+            }
+        });
+        assert_eq!(error, "#[griddb(nullable)] needs an Option<T> field");
+        let input: DeriveInput = parse_quote! {
+            struct Person {
+                #[griddb(nullable)]
+                lob: ::std::option::Option<Vec<u8>>,
+            }
+        };
+        assert!(expand_grid_row(&input).is_ok());
+    }
+
+    #[test]
+    fn key_cannot_be_option() {
+        let error = expand_error(parse_quote! {
+            struct Person {
+                #[griddb(key)]
+                name: Option<String>,
+            }
+        });
+        assert_eq!(error, "#[griddb(key)] column cannot be nullable");
+    }
+
+    #[test]
+    fn key_must_be_first_and_not_nullable() {
+        let error = expand_error(parse_quote! {
+            struct Person {
+                status: bool,
+                #[griddb(key)]
+                name: String,
+            }
+        });
+        assert_eq!(error, "#[griddb(key)] must be on the first field");
+        let error = expand_error(parse_quote! {
+            struct Person {
+                #[griddb(key, nullable)]
+                name: Option<String>,
+            }
+        });
+        assert_eq!(error, "#[griddb(key)] column cannot be nullable");
+    }
+
+    #[test]
+    fn unknown_attribute_is_rejected() {
+        let error = expand_error(parse_quote! {
+            struct Person {
+                #[griddb(primary)]
+                name: String,
+            }
+        });
+        assert_eq!(error, "expected `key`, `nullable` or `rename = \"...\"`");
+    }
+
+    #[test]
+    fn only_structs_with_named_fields() {
+        let error = expand_error(parse_quote! {
+            struct Person(String, i64);
+        });
+        assert_eq!(
+            error,
+            "GridRow can only be derived for structs with named fields"
+        );
+        let error = expand_error(parse_quote! {
+            enum Person {
+                Name(String),
+            }
+        });
+        assert_eq!(error, "GridRow can only be derived for structs");
+        let error = expand_error(parse_quote! {
+            struct Person {}
+        });
+        assert_eq!(error, "GridRow needs at least one field");
+    }
+}
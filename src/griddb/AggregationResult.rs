@@ -16,7 +16,9 @@
 
 extern crate griddb_sys as ffi;
 
+use crate::griddb::Error::*;
 use chrono::{DateTime, Utc};
+use std::ffi::c_void;
 use std::ptr;
 use std::time::{Duration, UNIX_EPOCH};
 
@@ -28,7 +30,7 @@ impl AggregationResult {
     pub fn new(ptr_agg: *mut ffi::GSAggregationResult) -> AggregationResult {
         AggregationResult { _ptr: ptr_agg }
     }
-    pub fn get_as_i64(&self) -> (i32, i64) {
+    pub fn get_as_i64(&self) -> Result<i64, GridDbError> {
         let result: *mut i64;
         let value_result: i64;
         let value_bool = ptr::null_mut();
@@ -39,9 +41,10 @@ impl AggregationResult {
             value_result = *result;
             ffi::free(result as *mut _);
         }
-        (ret, value_result)
+        GridDbError::check(ret, self._ptr as *mut c_void)?;
+        Ok(value_result)
     }
-    pub fn get_as_f64(&self) -> (i32, f64) {
+    pub fn get_as_f64(&self) -> Result<f64, GridDbError> {
         let result: *mut f64;
         let value_result: f64;
         let value_bool = ptr::null_mut();
@@ -52,9 +55,10 @@ impl AggregationResult {
             value_result = *result;
             ffi::free(result as *mut _);
         }
-        (ret, value_result)
+        GridDbError::check(ret, self._ptr as *mut c_void)?;
+        Ok(value_result)
     }
-    pub fn get_as_timestamp(&self) -> (i32, DateTime<Utc>) {
+    pub fn get_as_timestamp(&self) -> Result<DateTime<Utc>, GridDbError> {
         let result: *mut i64;
         let value_result: i64;
         let value_bool = ptr::null_mut();
@@ -65,9 +69,10 @@ impl AggregationResult {
             value_result = *result;
             ffi::free(result as *mut _);
         }
+        GridDbError::check(ret, self._ptr as *mut c_void)?;
         let timestamp = UNIX_EPOCH + Duration::from_millis(value_result as u64);
         // Return DateTime
-        (ret, DateTime::<Utc>::from(timestamp))
+        Ok(DateTime::<Utc>::from(timestamp))
     }
 }
 
@@ -0,0 +1,133 @@
+/*
+    Copyright (c) 2022 TOSHIBA Digital Solutions Corporation.
+
+    Licensed under the Apache License, Version 2.0 (the "License");
+    you may not use this file except in compliance with the License.
+    You may obtain a copy of the License at
+
+        http://www.apache.org/licenses/LICENSE-2.0
+
+    Unless required by applicable law or agreed to in writing, software
+    distributed under the License is distributed on an "AS IS" BASIS,
+    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+    See the License for the specific language governing permissions and
+    limitations under the License.
+*/
+
+extern crate griddb_sys as ffi;
+
+use crate::griddb::Const::*;
+use crate::griddb::Container::*;
+use crate::griddb::Error::*;
+use crate::griddb::Geometry::*;
+use crate::griddb::Query::*;
+use crate::griddb::Type::*;
+use std::ffi::CString;
+use std::ptr;
+
+// Class Collection: container of type Collection with the operations
+// specific to it. The generic operations are reached through container()
+pub struct Collection {
+    _container: Container,
+}
+
+impl Collection {
+    // Fails when container is not a Collection
+    pub fn new(container: Container) -> Result<Collection, GridDbError> {
+        if container.container_type != ContainerType::Collection {
+            return Err(GridDbError::new(
+                ERROR_INVALID_STATE,
+                "Container is not a Collection",
+            ));
+        }
+        Ok(Collection {
+            _container: container,
+        })
+    }
+
+    pub fn container(&self) -> &Container {
+        &self._container
+    }
+
+    // Rows whose GEOMETRY column matches geometry with operator
+    pub fn query_by_geometry(
+        &self,
+        column: &str,
+        geometry: &Geometry,
+        operator: GeometryOperator,
+    ) -> Result<Query, GridDbError> {
+        let container = &self._container;
+        let column_name = CString::new(column).expect("Error convert String to CString");
+        let wkt = to_wkt(geometry)?;
+        let mut query: *mut ffi::GSQuery = ptr::null_mut();
+        let ret;
+        unsafe {
+            ret = ffi::gsQueryByGeometry(
+                container.as_ptr(),
+                column_name.as_ptr(),
+                wkt.as_ptr(),
+                operator as i32,
+                &mut query,
+            );
+        }
+        container.check(ret)?;
+        Ok(container.wrap_query(query))
+    }
+
+    // Rows whose GEOMETRY column intersects include and does not intersect exclude
+    pub fn query_by_geometry_with_exclusion(
+        &self,
+        column: &str,
+        include: &Geometry,
+        exclude: &Geometry,
+    ) -> Result<Query, GridDbError> {
+        let container = &self._container;
+        let column_name = CString::new(column).expect("Error convert String to CString");
+        let include_wkt = to_wkt(include)?;
+        let exclude_wkt = to_wkt(exclude)?;
+        let mut query: *mut ffi::GSQuery = ptr::null_mut();
+        let ret;
+        unsafe {
+            ret = ffi::gsQueryByGeometryWithExclusion(
+                container.as_ptr(),
+                column_name.as_ptr(),
+                include_wkt.as_ptr(),
+                exclude_wkt.as_ptr(),
+                &mut query,
+            );
+        }
+        container.check(ret)?;
+        Ok(container.wrap_query(query))
+    }
+
+    // Same as query_by_geometry_with_exclusion(), kept for the C API name
+    pub fn query_by_geometry_with_disjoint_condition(
+        &self,
+        column: &str,
+        intersection: &Geometry,
+        disjoint: &Geometry,
+    ) -> Result<Query, GridDbError> {
+        let container = &self._container;
+        let column_name = CString::new(column).expect("Error convert String to CString");
+        let intersection_wkt = to_wkt(intersection)?;
+        let disjoint_wkt = to_wkt(disjoint)?;
+        let mut query: *mut ffi::GSQuery = ptr::null_mut();
+        let ret;
+        unsafe {
+            ret = ffi::gsQueryByGeometryWithDisjointCondition(
+                container.as_ptr(),
+                column_name.as_ptr(),
+                intersection_wkt.as_ptr(),
+                disjoint_wkt.as_ptr(),
+                &mut query,
+            );
+        }
+        container.check(ret)?;
+        Ok(container.wrap_query(query))
+    }
+}
+
+fn to_wkt(geometry: &Geometry) -> Result<CString, GridDbError> {
+    geometry.validate()?;
+    Ok(CString::new(geometry.to_wkt()).expect("Error convert String to CString"))
+}
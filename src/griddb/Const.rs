@@ -15,3 +15,7 @@
 */
 
 pub const ERROR_CONVERT_DATA: i32 = -1;
+// No container has the given name
+pub const ERROR_CONTAINER_NOT_FOUND: i32 = -2;
+// Operation is not allowed for the container or its transaction mode
+pub const ERROR_INVALID_STATE: i32 = -3;
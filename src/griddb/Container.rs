@@ -17,13 +17,22 @@
 extern crate griddb_sys as ffi;
 
 use crate::griddb::Const::*;
+use crate::griddb::ContainerInfo::*;
+use crate::griddb::Error::*;
+use crate::griddb::IndexInfo::*;
 use crate::griddb::Query::*;
+use crate::griddb::RetryPolicy::*;
+use crate::griddb::Row::*;
+use crate::griddb::TriggerInfo::*;
 use crate::griddb::Type::*;
 use crate::griddb::Util::*;
 use crate::griddb::Value::*;
 use std::any::Any;
+use std::cell::Cell;
+use std::ffi::c_void;
 use std::ffi::CString;
 use std::ptr;
+use std::rc::Rc;
 
 pub type GSContainer = ffi::GSContainerTag;
 pub type GSRow = ffi::GSRowTag;
@@ -34,323 +43,556 @@ pub struct Container {
     _ptr: *mut GSContainer,
     // _row attribute support query data
     _row: *mut GSRow,
+    _name_list: Vec<String>,
     _type_list: Vec<Type>,
+    _nullable_list: Vec<bool>,
+    _row_key: bool,
+    _row_key_column_list: Vec<i32>,
+    // Schema of the row key columns, to build keys for gsGetRowGeneral / gsDeleteRowGeneral
+    _row_key_info: Option<ContainerInfo>,
+    _store: *mut ffi::GSGridStore,
+    // Shared with the queries of the container
+    _auto_commit: Rc<Cell<bool>>,
+    _retry: Rc<RetryHandler>,
     pub container_type: ContainerType,
 }
 #[allow(clippy::not_unsafe_ptr_arg_deref)]
 impl Container {
+    // Number of rows sent by one gsPutMultipleRows call in put_rows()
+    pub const PUT_ROWS_CHUNK_SIZE: usize = 10000;
+
     pub fn new(
         container: *mut GSContainer,
-        container_type: ContainerType,
-        type_list: Vec<Type>,
-    ) -> Result<Container, i32> {
+        store: *mut ffi::GSGridStore,
+        container_info: &ContainerInfo,
+        retry: Rc<RetryHandler>,
+    ) -> Result<Container, GridDbError> {
+        let column_info_list = &container_info.column_info_list;
         let mut gs_row: *mut GSRow = ptr::null_mut();
         let ret;
         unsafe {
             ret = ffi::gsCreateRowByContainer(container, &mut gs_row);
         }
-        if ret == ffi::GS_RESULT_OK as i32 {
-            Ok(Container {
-                _ptr: container,
-                _row: gs_row,
-                _type_list: type_list,
-                container_type,
+        GridDbError::check(ret, container as *mut c_void)?;
+        let name_list = column_info_list
+            .iter()
+            .map(|column| column.0.clone())
+            .collect();
+        let type_list = column_info_list.iter().map(|column| column.1).collect();
+        let nullable_list = column_info_list
+            .iter()
+            .map(|column| column.2 == TypeOption::Nullable)
+            .collect();
+        Ok(Container {
+            _ptr: container,
+            _row: gs_row,
+            _name_list: name_list,
+            _type_list: type_list,
+            _nullable_list: nullable_list,
+            _row_key: container_info.row_key,
+            _row_key_column_list: container_info.row_key_column_list.clone(),
+            _row_key_info: if container_info.row_key {
+                Some(container_info.row_key_info())
+            } else {
+                None
+            },
+            _store: store,
+            _auto_commit: Rc::new(Cell::new(true)),
+            _retry: retry,
+            container_type: container_info.container_type.clone(),
+        })
+    }
+
+    // Name, type and nullability of every column, in row order
+    pub fn column_info_list(&self) -> Vec<(String, Type, TypeOption)> {
+        self._name_list
+            .iter()
+            .zip(&self._type_list)
+            .zip(&self._nullable_list)
+            .map(|((name, column_type), nullable)| {
+                let option = if *nullable {
+                    TypeOption::Nullable
+                } else {
+                    TypeOption::NotNull
+                };
+                (name.clone(), *column_type, option)
             })
-        } else {
-            Err(ret)
-        }
+            .collect()
     }
 
-    // Container get row
-    fn get_row_by_string(&self, value: &str) -> (i32, i8) {
-        let key_tmp = CString::new(value).unwrap();
-        let mut b_exist: ffi::GSBool = ffi::GS_TRUE as i8;
-        let b_exist_ptr: *mut ffi::GSBool = &mut b_exist;
-        let ret;
-        let for_update = ffi::GS_FALSE;
+    pub fn row_key(&self) -> bool {
+        self._row_key
+    }
 
-        unsafe {
-            ret = ffi::gsGetRowByString(
-                self._ptr,
-                key_tmp.as_ptr(),
-                self._row as *mut std::ffi::c_void,
-                for_update as i8,
-                b_exist_ptr,
-            );
-        }
+    // Positions of the row key columns
+    pub fn row_key_column_list(&self) -> &[i32] {
+        &self._row_key_column_list
+    }
 
-        (ret, b_exist)
+    fn composite_key_error() -> GridDbError {
+        GridDbError::new(
+            ERROR_INVALID_STATE,
+            "Container has a composite row key, use get_by_keys(), get_by_keys_for_update() or remove_by_keys()",
+        )
     }
 
-    fn get_row_by_integer(&self, value: i32) -> (i32, i8) {
-        let mut b_exist: ffi::GSBool = ffi::GS_TRUE as i8;
-        let b_exist_ptr: *mut ffi::GSBool = &mut b_exist;
-        let ret;
-        let for_update = ffi::GS_FALSE;
-        unsafe {
-            ret = ffi::gsGetRowByInteger(
-                self._ptr,
-                value,
-                self._row as *mut std::ffi::c_void,
-                for_update as i8,
-                b_exist_ptr,
-            );
+    pub(crate) fn as_ptr(&self) -> *mut GSContainer {
+        self._ptr
+    }
+
+    pub(crate) fn check(&self, ret: i32) -> Result<(), GridDbError> {
+        GridDbError::check(ret, self._ptr as *mut c_void)
+    }
+
+    pub(crate) fn retry(&self) -> &RetryHandler {
+        &self._retry
+    }
+
+    // Row object of the container, filled by the gsGetRow* family
+    pub(crate) fn row_ptr(&self) -> *mut GSRow {
+        self._row
+    }
+
+    // Values of the row object once a gsGetRow* call returned, empty when no row exists
+    pub(crate) fn read_row(
+        &self,
+        ret: i32,
+        row_exist: ffi::GSBool,
+    ) -> Result<Vec<Value>, GridDbError> {
+        self.check(ret)?;
+        let mut vec = Vec::new();
+        if row_exist != ffi::GS_TRUE as i8 {
+            return Ok(vec);
         }
-        (ret, b_exist)
+        Util::get_row_data(self._row, &mut vec, &self._type_list)?;
+        Ok(vec)
     }
 
-    fn get_row_by_long(&self, value: i64) -> (i32, i8) {
-        let mut b_exist: ffi::GSBool = ffi::GS_TRUE as i8;
-        let b_exist_ptr: *mut ffi::GSBool = &mut b_exist;
-        let ret;
-        let for_update = ffi::GS_FALSE;
-        unsafe {
-            ret = ffi::gsGetRowByLong(
-                self._ptr,
-                value,
-                self._row as *mut std::ffi::c_void,
-                for_update as i8,
-                b_exist_ptr,
-            );
+    fn key_type_error() -> GridDbError {
+        GridDbError::new(
+            ERROR_CONVERT_DATA,
+            "Key must be a Value, &str, String, i8, i16, i32, i64 or Timestamp",
+        )
+    }
+
+    pub fn get<T: Any>(&self, value: T) -> Result<Vec<Value>, GridDbError> {
+        let key = self.key_value(&value)?;
+        self.get_by_keys(&[key])
+    }
+
+    // Get the row and lock it until the end of the current transaction, so
+    // that it can be updated without a race with other writers.
+    // Auto-commit must be disabled with set_auto_commit(false)
+    pub fn get_for_update<T: Any>(&self, value: T) -> Result<Vec<Value>, GridDbError> {
+        self.check_manual_commit("get_for_update")?;
+        let key = self.key_value(&value)?;
+        self.get_row_general(&[key], true)
+    }
+
+    // get_for_update() for any row key including a composite one
+    pub fn get_by_keys_for_update(&self, key: &[Value]) -> Result<Vec<Value>, GridDbError> {
+        self.check_manual_commit("get_by_keys_for_update")?;
+        self.get_row_general(key, true)
+    }
+
+    fn check_manual_commit(&self, operation: &str) -> Result<(), GridDbError> {
+        Util::check_manual_commit(self._auto_commit.get(), operation)
+    }
+
+    // Get the row whose row key is key, empty when no row exists
+    pub fn get_by_key(&self, key: &Value) -> Result<Vec<Value>, GridDbError> {
+        self.check_single_key()?;
+        self.get_by_keys(std::slice::from_ref(key))
+    }
+
+    // Get the row whose row key columns have the values of key, for any row
+    // key including a composite one. Empty when no row exists
+    pub fn get_by_keys(&self, key: &[Value]) -> Result<Vec<Value>, GridDbError> {
+        self._retry
+            .run(OperationKind::Get, || self.get_row_general(key, false))
+    }
+
+    fn create_row_key(&self, key: &[Value]) -> Result<*mut ffi::GSRowKey, GridDbError> {
+        match &self._row_key_info {
+            Some(key_info) => Util::create_row_key(self._store, key_info, key),
+            None => Err(GridDbError::new(
+                ERROR_INVALID_STATE,
+                "Container has no row key",
+            )),
         }
-        (ret, b_exist)
     }
 
-    fn get_row_by_timestamp(&self, value: i64) -> (i32, i8) {
-        let mut b_exist: ffi::GSBool = ffi::GS_TRUE as i8;
-        let b_exist_ptr: *mut ffi::GSBool = &mut b_exist;
+    fn get_row_general(&self, key: &[Value], for_update: bool) -> Result<Vec<Value>, GridDbError> {
+        let mut gs_key = self.create_row_key(key)?;
+        let mut row_exist: ffi::GSBool = ffi::GS_FALSE as i8;
         let ret;
-        let for_update = ffi::GS_FALSE;
         unsafe {
-            ret = ffi::gsGetRowByTimestamp(
+            ret = ffi::gsGetRowGeneral(
                 self._ptr,
-                value,
-                self._row as *mut std::ffi::c_void,
+                gs_key,
+                self._row,
                 for_update as i8,
-                b_exist_ptr,
+                &mut row_exist,
             );
+            ffi::gsCloseRow(&mut gs_key);
         }
-        (ret, b_exist)
+        self.read_row(ret, row_exist)
     }
 
-    pub fn get<T: Any>(&self, value: T) -> Result<Vec<Value>, i32> {
-        let value_any = &value as &dyn Any;
-        let mut ret: i32;
-        let row_exist: i8;
-
-        let mut vec = Vec::new();
-        match self._type_list[0] {
-            Type::String => {
-                match value_any.downcast_ref::<&str>() {
-                    Some(as_string) => {
-                        let (ret_func, exist) = self.get_row_by_string(*as_string);
-                        ret = ret_func;
-                        row_exist = exist;
-                    }
-                    None => {
-                        return Err(ERROR_CONVERT_DATA);
-                    }
-                };
-            }
-            Type::Long => {
-                match value_any.downcast_ref::<i64>() {
-                    Some(value) => {
-                        let (ret_func, exist) = self.get_row_by_long(*value);
-                        ret = ret_func;
-                        row_exist = exist;
-                    }
-                    None => return Err(ERROR_CONVERT_DATA),
-                };
-            }
-
-            Type::Integer => {
-                match value_any.downcast_ref::<i32>() {
-                    Some(value) => {
-                        let (ret_func, exist) = self.get_row_by_integer(*value);
-                        ret = ret_func;
-                        row_exist = exist;
-                    }
-                    None => return Err(ERROR_CONVERT_DATA),
-                };
-            }
-            Type::Timestamp => {
-                match value_any.downcast_ref::<Timestamp>() {
-                    Some(value) => {
-                        let (ret_func, exist) = self.get_row_by_timestamp((*value).value);
-                        ret = ret_func;
-                        row_exist = exist;
-                    }
-                    None => return Err(ERROR_CONVERT_DATA),
-                };
-            }
-            _ => {
-                return Err(ERROR_CONVERT_DATA);
-            }
+    // get(), get_for_update() and remove() take the value of a single row key
+    fn check_single_key(&self) -> Result<(), GridDbError> {
+        if self._row_key_column_list.len() > 1 {
+            return Err(Container::composite_key_error());
         }
+        Ok(())
+    }
 
-        if ret != ffi::GS_RESULT_OK as i32 {
-            return Err(ret);
+    // Convert the key given to get() or remove() into a Value. Its type is
+    // checked against the row key column when the row key is created
+    fn key_value<T: Any>(&self, value: &T) -> Result<Value, GridDbError> {
+        self.check_single_key()?;
+        let value_any = value as &dyn Any;
+        if let Some(value) = value_any.downcast_ref::<Value>() {
+            return Ok(value.clone());
         }
-        if row_exist != ffi::GS_TRUE as i8 {
-            // When row is not existed, return empty vector
-            return Ok(vec);
+        if let Some(value) = value_any.downcast_ref::<&str>() {
+            return Ok(Value::Str(value.to_string()));
         }
-        ret = Util::get_row_data(self._row, &mut vec, &self._type_list);
-        if ret != ffi::GS_RESULT_OK as i32 {
-            Err(ret)
-        } else {
-            Ok(vec)
+        if let Some(value) = value_any.downcast_ref::<String>() {
+            return Ok(Value::Str(value.clone()));
+        }
+        if let Some(value) = value_any.downcast_ref::<i8>() {
+            return Ok(Value::Byte(*value));
+        }
+        if let Some(value) = value_any.downcast_ref::<i16>() {
+            return Ok(Value::Short(*value));
         }
+        if let Some(value) = value_any.downcast_ref::<i32>() {
+            return Ok(Value::Integer(*value));
+        }
+        if let Some(value) = value_any.downcast_ref::<i64>() {
+            return Ok(Value::Long(*value));
+        }
+        if let Some(value) = value_any.downcast_ref::<Timestamp>() {
+            return Ok(Value::Timestamp(*value));
+        }
+        Err(Container::key_type_error())
     }
+
     // container query
-    pub fn query(&self, query: &str) -> Result<Query, i32> {
+    pub fn query(&self, query: &str) -> Result<Query, GridDbError> {
         let mut p_query: *mut ffi::GSQuery = ptr::null_mut();
         let value = CString::new(query).unwrap();
         let ret;
         unsafe {
             ret = ffi::gsQuery(self._ptr, value.as_ptr(), &mut p_query);
         }
-        if ret == ffi::GS_RESULT_OK as i32 {
-            Ok(Query::new(p_query, self._row, self._type_list.to_vec()))
-        } else {
-            Err(ret)
-        }
+        self.check(ret)?;
+        Ok(self.wrap_query(p_query))
+    }
+
+    // Query on this container, for a GSQuery made by any gsQuery* function
+    pub(crate) fn wrap_query(&self, query: *mut ffi::GSQuery) -> Query {
+        Query::new(
+            query,
+            self._row,
+            self._name_list.to_vec(),
+            self._type_list.to_vec(),
+            self._nullable_list.to_vec(),
+            self._auto_commit.clone(),
+            self._retry.clone(),
+        )
     }
-    pub fn create_index(&self, name: &str, flags: IndexType) -> i32 {
+    pub fn create_index(&self, name: &str, flags: IndexType) -> Result<(), GridDbError> {
         let c_string = CString::new(name).unwrap();
         let ret;
         unsafe {
             ret = ffi::gsCreateIndex(self._ptr, c_string.as_ptr(), flags as i32);
         }
-        ret
+        self.check(ret)
     }
 
-    pub fn drop_index(&self, name: &str, flags: IndexType) -> i32 {
+    pub fn drop_index(&self, name: &str, flags: IndexType) -> Result<(), GridDbError> {
         let c_string = CString::new(name).unwrap();
         let ret;
         unsafe {
             ret = ffi::gsDropIndex(self._ptr, c_string.as_ptr(), flags as i32);
         }
-        ret
+        self.check(ret)
     }
 
-    pub fn set_auto_commit(&self, enabled: bool) -> i32 {
-        let gs_enabled: ffi::GSBool = if enabled {
-            ffi::GS_TRUE as i8
-        } else {
-            ffi::GS_FALSE as i8
-        };
+    // Create a named, composite or hash index
+    pub fn create_index_detail(&self, index_info: &IndexInfo) -> Result<(), GridDbError> {
+        let buffer = GsIndexInfo::new(index_info);
+        let gs_info = buffer.unwrap();
         let ret;
         unsafe {
-            ret = ffi::gsSetAutoCommit(self._ptr, gs_enabled);
+            ret = ffi::gsCreateIndexDetailV4_3(self._ptr, &gs_info);
         }
-        ret
+        self.check(ret)
     }
 
-    pub fn commit(&self) -> i32 {
+    // Drop the indexes matching index_info, fields left empty match any index
+    pub fn drop_index_detail(&self, index_info: &IndexInfo) -> Result<(), GridDbError> {
+        let buffer = GsIndexInfo::new(index_info);
+        let gs_info = buffer.unwrap();
         let ret;
         unsafe {
-            ret = ffi::gsCommit(self._ptr);
+            ret = ffi::gsDropIndexDetailV4_3(self._ptr, &gs_info);
         }
-        ret
+        self.check(ret)
     }
 
-    fn remove_by_string(&self, value: &str) -> i32 {
-        let key_tmp = CString::new(value).unwrap();
-        let b_exit: *mut ffi::GSBool = ptr::null_mut();
+    // Triggers of the container are listed by Store::get_container_info()
+    pub fn create_trigger(&self, trigger_info: &TriggerInfo) -> Result<(), GridDbError> {
+        let buffer = GsTriggerInfo::new(trigger_info);
+        let gs_info = buffer.unwrap();
         let ret;
         unsafe {
-            ret = ffi::gsRemoveRowByString(self._ptr, key_tmp.as_ptr(), b_exit);
+            ret = ffi::gsCreateTrigger(self._ptr, &gs_info);
         }
-        ret
+        self.check(ret)
     }
 
-    fn remove_by_integer(&self, value: i32) -> i32 {
-        let b_exit: *mut ffi::GSBool = ptr::null_mut();
+    pub fn drop_trigger(&self, name: &str) -> Result<(), GridDbError> {
+        let c_string = CString::new(name).unwrap();
         let ret;
         unsafe {
-            ret = ffi::gsDeleteRowByInteger(self._ptr, value, b_exit);
+            ret = ffi::gsDropTrigger(self._ptr, c_string.as_ptr());
         }
-        ret
+        self.check(ret)
     }
 
-    fn remove_by_long(&self, value: i64) -> i32 {
-        let b_exit: *mut ffi::GSBool = ptr::null_mut();
+    pub fn set_auto_commit(&self, enabled: bool) -> Result<(), GridDbError> {
+        let gs_enabled: ffi::GSBool = if enabled {
+            ffi::GS_TRUE as i8
+        } else {
+            ffi::GS_FALSE as i8
+        };
         let ret;
         unsafe {
-            ret = ffi::gsDeleteRowByLong(self._ptr, value, b_exit);
+            ret = ffi::gsSetAutoCommit(self._ptr, gs_enabled);
         }
-        ret
+        self.check(ret)?;
+        self._auto_commit.set(enabled);
+        Ok(())
     }
 
-    fn remove_by_timestamp(&self, value: i64) -> i32 {
-        let b_exit: *mut ffi::GSBool = ptr::null_mut();
+    pub fn commit(&self) -> Result<(), GridDbError> {
         let ret;
         unsafe {
-            ret = ffi::gsDeleteRowByTimestamp(self._ptr, value, b_exit);
+            ret = ffi::gsCommit(self._ptr);
         }
-        ret
+        self.check(ret)
     }
 
-    pub fn remove<T: Any>(&self, value: T) -> i32 {
-        let value_any = &value as &dyn Any;
-        match self._type_list[0] {
-            Type::String => match value_any.downcast_ref::<&str>() {
-                Some(as_string) => self.remove_by_string(*as_string),
-                None => ERROR_CONVERT_DATA,
-            },
-            Type::Integer => match value_any.downcast_ref::<i32>() {
-                Some(value) => self.remove_by_integer(*value),
-                None => ERROR_CONVERT_DATA,
-            },
+    // Run operation in a transaction committed when it succeeds and aborted
+    // otherwise. With auto-commit disabled, operation runs in the current
+    // transaction, which is left to the caller.
+    // When abort fails, its error is returned and auto-commit stays disabled,
+    // since enabling it would commit what operation did
+    fn in_transaction<T, F>(&self, operation: F) -> Result<T, GridDbError>
+    where
+        F: FnOnce() -> Result<T, GridDbError>,
+    {
+        if !self._auto_commit.get() {
+            return operation();
+        }
+        self.set_auto_commit(false)?;
+        let result = operation().and_then(|value| self.commit().map(|()| value));
+        if let Err(error) = &result {
+            self.abort().map_err(|abort_error| {
+                abort_error.with_context(&format!(
+                    "Transaction cannot be aborted after error \"{}\", auto-commit is left disabled",
+                    error
+                ))
+            })?;
+        }
+        let restored = self.set_auto_commit(true);
+        let value = result?;
+        restored?;
+        Ok(value)
+    }
 
-            Type::Long => match value_any.downcast_ref::<i64>() {
-                Some(value) => self.remove_by_long(*value),
-                None => ERROR_CONVERT_DATA,
-            },
-            Type::Timestamp => match value_any.downcast_ref::<Timestamp>() {
-                Some(value) => self.remove_by_timestamp((*value).value),
-                None => ERROR_CONVERT_DATA,
-            },
-            _ => ERROR_CONVERT_DATA,
+    // Update the rows matched by the TQL query tql with the values returned by
+    // update, rows for which it returns None are left unchanged.
+    // Returns the number of updated rows
+    pub fn update_where<F>(&self, tql: &str, mut update: F) -> Result<usize, GridDbError>
+    where
+        F: FnMut(&Row) -> Option<Vec<Value>>,
+    {
+        self.in_transaction(|| {
+            let query = self.query(tql)?;
+            let mut row_set = query.fetch_for_update()?;
+            let mut count = 0;
+            while let Some(row) = row_set.next() {
+                if let Some(fields) = update(&row?) {
+                    row_set.update_current(fields)?;
+                    count += 1;
+                }
+            }
+            Ok(count)
+        })
+    }
+
+    // Delete the rows matched by the TQL query tql, returns their number
+    pub fn delete_where(&self, tql: &str) -> Result<usize, GridDbError> {
+        self.in_transaction(|| {
+            let query = self.query(tql)?;
+            let row_set = query.fetch_for_update()?;
+            let mut count = 0;
+            while row_set.has_next() {
+                row_set.next_values()?;
+                row_set.delete_current()?;
+                count += 1;
+            }
+            Ok(count)
+        })
+    }
+
+    pub fn remove<T: Any>(&self, value: T) -> Result<(), GridDbError> {
+        let key = self.key_value(&value)?;
+        self.remove_by_keys(&[key])
+    }
+
+    // Remove the row whose row key columns have the values of key
+    pub fn remove_by_keys(&self, key: &[Value]) -> Result<(), GridDbError> {
+        let mut gs_key = self.create_row_key(key)?;
+        let ret;
+        unsafe {
+            ret = ffi::gsDeleteRowGeneral(self._ptr, gs_key, ptr::null_mut());
+            ffi::gsCloseRow(&mut gs_key);
         }
+        self.check(ret)
     }
 
-    pub fn flush(&self) -> i32 {
+    pub fn flush(&self) -> Result<(), GridDbError> {
         let ret;
         unsafe {
             ret = ffi::gsFlush(self._ptr);
         }
-        ret
+        self.check(ret)
     }
 
-    pub fn abort(&self) -> i32 {
+    pub fn abort(&self) -> Result<(), GridDbError> {
         let ret;
         unsafe {
             ret = ffi::gsAbort(self._ptr);
         }
-        ret
+        self.check(ret)
     }
-    pub fn put(&self, fields: Vec<Value>) -> i32 {
-        if fields.len() != self._type_list.len() {
-            return ERROR_CONVERT_DATA;
+    // Check a row against the schema before it is bound
+    pub(crate) fn validate_row(&self, fields: &[Value]) -> Result<(), GridDbError> {
+        Util::check_row(fields, &self._type_list, &self._nullable_list)
+    }
+
+    // Run a put, retried only when it is idempotent
+    fn run_put<F>(&self, mut put: F) -> Result<(), GridDbError>
+    where
+        F: FnMut() -> Result<(), GridDbError>,
+    {
+        // Only an auto-committed put on a row key overwrites the same row when repeated
+        if self._row_key && self._auto_commit.get() {
+            self._retry.run(OperationKind::Put, put)
+        } else {
+            put()
         }
+    }
+
+    pub fn put(&self, fields: Vec<Value>) -> Result<(), GridDbError> {
+        self.validate_row(&fields)?;
         let row = self._row;
-        for (pos, e) in fields.iter().enumerate() {
-            if e.data_type() != self._type_list[pos] {
-                return ERROR_CONVERT_DATA;
+        Util::bind_row(&fields, row)?;
+
+        self.run_put(|| {
+            let key = ptr::null_mut();
+            let b_exit = ptr::null_mut();
+            let result;
+            unsafe {
+                result = ffi::gsPutRow(self._ptr, key, row as *const std::ffi::c_void, b_exit);
             }
-            e.bind(pos as i32, row);
+            self.check(result)
+        })
+    }
+
+    // Put rows with gsPutMultipleRows, PUT_ROWS_CHUNK_SIZE rows per call.
+    // Every row is validated before the first call. With auto-commit, more
+    // than one chunk is put in a single transaction, so that no row is stored
+    // when a chunk is rejected. Without auto-commit, the rows are part of the
+    // current transaction
+    pub fn put_rows(&self, rows: Vec<Vec<Value>>) -> Result<(), GridDbError> {
+        for (index, fields) in rows.iter().enumerate() {
+            self.validate_row(fields)
+                .map_err(|error| error.with_context(&format!("Row {} is invalid", index)))?;
         }
+        if rows.is_empty() {
+            return Ok(());
+        }
+        if rows.len() <= Container::PUT_ROWS_CHUNK_SIZE {
+            return self.put_chunk(&rows, 0);
+        }
+        self.in_transaction(|| {
+            for (chunk_index, chunk) in rows.chunks(Container::PUT_ROWS_CHUNK_SIZE).enumerate() {
+                self.put_chunk(chunk, chunk_index * Container::PUT_ROWS_CHUNK_SIZE)?;
+            }
+            Ok(())
+        })
+    }
 
-        let key = ptr::null_mut();
-        let b_exit = ptr::null_mut();
-        let result;
-        unsafe {
-            result = ffi::gsPutRow(self._ptr, key, row as *const std::ffi::c_void, b_exit);
+    // start is the index of chunk[0] in the rows given to put_rows()
+    fn put_chunk(&self, chunk: &[Vec<Value>], start: usize) -> Result<(), GridDbError> {
+        let mut row_list: Vec<*mut GSRow> = Vec::with_capacity(chunk.len());
+        let result = self.bind_chunk(chunk, start, &mut row_list).and_then(|()| {
+            let row_objs: Vec<*const c_void> =
+                row_list.iter().map(|row| *row as *const c_void).collect();
+            self.run_put(|| {
+                let ret;
+                unsafe {
+                    ret = ffi::gsPutMultipleRows(
+                        self._ptr,
+                        row_objs.as_ptr(),
+                        row_objs.len() as ffi::size_t,
+                        ptr::null_mut(),
+                    );
+                }
+                self.check(ret)
+            })
+            .map_err(|error| {
+                error.with_context(&format!(
+                    "Rows {} to {} were rejected",
+                    start,
+                    start + chunk.len() - 1
+                ))
+            })
+        });
+        for row in row_list.iter_mut() {
+            unsafe {
+                ffi::gsCloseRow(row);
+            }
         }
         result
     }
+
+    fn bind_chunk(
+        &self,
+        chunk: &[Vec<Value>],
+        start: usize,
+        row_list: &mut Vec<*mut GSRow>,
+    ) -> Result<(), GridDbError> {
+        for (offset, fields) in chunk.iter().enumerate() {
+            let mut gs_row: *mut GSRow = ptr::null_mut();
+            let ret;
+            unsafe {
+                ret = ffi::gsCreateRowByContainer(self._ptr, &mut gs_row);
+            }
+            self.check(ret)?;
+            row_list.push(gs_row);
+            Util::bind_row(fields, gs_row).map_err(|error| {
+                error.with_context(&format!("Row {} cannot be bound", start + offset))
+            })?;
+        }
+        Ok(())
+    }
 }
 
 // Destructor
@@ -16,6 +16,9 @@
 
 extern crate griddb_sys as ffi;
 
+use crate::griddb::IndexInfo::*;
+use crate::griddb::TimeSeriesProperties::*;
+use crate::griddb::TriggerInfo::*;
 use crate::griddb::Type::*;
 use std::ffi::CString;
 use std::ptr;
@@ -27,34 +30,77 @@ pub struct ContainerInfo {
     // public attribute follow reference
     pub column_info_list: Vec<(String, Type, TypeOption)>,
     pub row_key: bool,
-    // Support put container
+    // Positions of the row key columns, [0] for a single column row key
+    pub row_key_column_list: Vec<i32>,
+    // Indexes created with the container, changed by set_index_info_list()
+    pub index_info_list: Vec<IndexInfo>,
+    // Only for a TimeSeries, changed by set_time_series_properties()
+    pub time_series_properties: Option<TimeSeriesProperties>,
+    // Triggers created with the container, changed by set_trigger_info_list()
+    pub trigger_info_list: Vec<TriggerInfo>,
+    // Support put container, _column_info_list points to _column_name_list
+    _column_name_list: Vec<CString>,
     _column_info_list: Vec<ffi::GSColumnInfo>,
+    _index_buffer_list: Vec<GsIndexInfo>,
+    _gs_index_info_list: Vec<ffi::GSIndexInfo>,
+    _gs_time_series_properties: Option<GsTimeSeriesProperties>,
+    _trigger_buffer_list: Vec<GsTriggerInfo>,
+    _gs_trigger_info_list: Vec<ffi::GSTriggerInfo>,
 }
 
 impl ContainerInfo {
+    // The first column is NOT NULL, the others are nullable
     pub fn ContainerInfo(
         name: &str,
         col_info: Vec<(&str, Type)>,
         container_type: ContainerType,
         row_key: bool,
+    ) -> ContainerInfo {
+        let mut col_info_with_option: Vec<(&str, Type, TypeOption)> = vec![];
+        for (column_name, col_type) in col_info {
+            let option_type: TypeOption = match col_info_with_option.len() {
+                0 => TypeOption::NotNull,
+                _ => TypeOption::Nullable,
+            };
+            col_info_with_option.push((column_name, col_type, option_type));
+        }
+        ContainerInfo::with_options(name, col_info_with_option, container_type, row_key)
+    }
+
+    // Nullability of every column is given by the caller
+    pub fn with_options(
+        name: &str,
+        col_info: Vec<(&str, Type, TypeOption)>,
+        container_type: ContainerType,
+        row_key: bool,
+    ) -> ContainerInfo {
+        let row_key_column_list = if row_key { vec![0] } else { vec![] };
+        ContainerInfo::with_row_key_columns(name, col_info, container_type, row_key_column_list)
+    }
+
+    // Row key made of the columns at the given positions, e.g. [0, 1, 2] for a
+    // composite row key on the first three columns. No row key when empty
+    pub fn with_row_key_columns(
+        name: &str,
+        col_info: Vec<(&str, Type, TypeOption)>,
+        container_type: ContainerType,
+        row_key_column_list: Vec<i32>,
     ) -> ContainerInfo {
         let mut vec_col_info: Vec<(String, Type, TypeOption)> = vec![];
+        let mut column_name_list: Vec<CString> = vec![];
         let mut gs_col_info: Vec<ffi::GSColumnInfo> = vec![];
         let mut _number_column = 0;
-        for (column_name, col_type) in col_info {
+        for (column_name, col_type, option_type) in col_info {
             let c_string = column_name.to_string();
             let c_string_vec = CString::new(column_name).unwrap();
-            let option_type: TypeOption = match vec_col_info.len() {
-                0 => TypeOption::NotNull,
-                _ => TypeOption::Nullable,
-            };
             vec_col_info.push((c_string, col_type, option_type));
             gs_col_info.push(ffi::GSColumnInfo {
-                name: c_string_vec.into_raw(),
+                name: c_string_vec.as_ptr(),
                 type_: col_type as i32,
                 indexTypeFlags: ffi::GSIndexTypeFlagTag_GS_INDEX_FLAG_DEFAULT,
                 options: option_type as i32,
             });
+            column_name_list.push(c_string_vec);
             _number_column += 1;
         }
         ContainerInfo {
@@ -62,11 +108,69 @@ impl ContainerInfo {
             container_type,
             column_count: _number_column as u64,
             column_info_list: vec_col_info,
-            row_key,
+            row_key: !row_key_column_list.is_empty(),
+            row_key_column_list,
+            index_info_list: vec![],
+            time_series_properties: None,
+            trigger_info_list: vec![],
+            _column_name_list: column_name_list,
             _column_info_list: gs_col_info,
+            _index_buffer_list: vec![],
+            _gs_index_info_list: vec![],
+            _gs_time_series_properties: None,
+            _trigger_buffer_list: vec![],
+            _gs_trigger_info_list: vec![],
         }
     }
 
+    pub fn set_index_info_list(&mut self, index_info_list: Vec<IndexInfo>) {
+        self._index_buffer_list = index_info_list.iter().map(GsIndexInfo::new).collect();
+        self._gs_index_info_list = self
+            ._index_buffer_list
+            .iter()
+            .map(|buffer| buffer.unwrap())
+            .collect();
+        self.index_info_list = index_info_list;
+    }
+
+    pub fn set_trigger_info_list(&mut self, trigger_info_list: Vec<TriggerInfo>) {
+        self._trigger_buffer_list = trigger_info_list.iter().map(GsTriggerInfo::new).collect();
+        self._gs_trigger_info_list = self
+            ._trigger_buffer_list
+            .iter()
+            .map(|buffer| buffer.unwrap())
+            .collect();
+        self.trigger_info_list = trigger_info_list;
+    }
+
+    pub fn set_time_series_properties(&mut self, properties: Option<TimeSeriesProperties>) {
+        self._gs_time_series_properties = properties.as_ref().map(GsTimeSeriesProperties::new);
+        self.time_series_properties = properties;
+    }
+
+    pub fn is_composite_row_key(&self) -> bool {
+        self.row_key_column_list.len() > 1
+    }
+
+    // Schema of the row key columns only, used to build row key objects
+    pub fn row_key_info(&self) -> ContainerInfo {
+        let col_info = self
+            .row_key_column_list
+            .iter()
+            .map(|pos| {
+                let column = &self.column_info_list[*pos as usize];
+                (column.0.as_str(), column.1, TypeOption::NotNull)
+            })
+            .collect();
+        let row_key_column_list = (0..self.row_key_column_list.len() as i32).collect();
+        ContainerInfo::with_row_key_columns(
+            &self.name,
+            col_info,
+            self.container_type.clone(),
+            row_key_column_list,
+        )
+    }
+
     pub fn unwrap(&self) -> ffi::GSContainerInfo {
         ffi::GSContainerInfo {
             name: ptr::null_mut(),
@@ -76,14 +180,33 @@ impl ContainerInfo {
             rowKeyAssigned: self.row_key as i8,
             // Default value
             columnOrderIgnorable: false as i8,
-            timeSeriesProperties: ptr::null_mut(),
-            triggerInfoCount: 0,
-            triggerInfoList: ptr::null_mut(),
+            timeSeriesProperties: self
+                ._gs_time_series_properties
+                .as_ref()
+                .map_or(ptr::null(), |properties| properties.as_ptr()),
+            triggerInfoCount: self._gs_trigger_info_list.len() as u64,
+            triggerInfoList: if self._gs_trigger_info_list.is_empty() {
+                ptr::null()
+            } else {
+                self._gs_trigger_info_list.as_ptr()
+            },
             dataAffinity: ptr::null_mut(),
-            indexInfoCount: 0,
-            indexInfoList: ptr::null_mut(),
-            rowKeyColumnCount: 0,
-            rowKeyColumnList: ptr::null_mut(),
+            indexInfoCount: self._gs_index_info_list.len() as u64,
+            indexInfoList: if self._gs_index_info_list.is_empty() {
+                ptr::null()
+            } else {
+                self._gs_index_info_list.as_ptr()
+            },
+            rowKeyColumnCount: if self.row_key {
+                self.row_key_column_list.len() as u64
+            } else {
+                0
+            },
+            rowKeyColumnList: if self.row_key && !self.row_key_column_list.is_empty() {
+                self.row_key_column_list.as_ptr()
+            } else {
+                ptr::null()
+            },
         }
     }
 }
@@ -0,0 +1,198 @@
+/*
+    Copyright (c) 2022 TOSHIBA Digital Solutions Corporation.
+
+    Licensed under the Apache License, Version 2.0 (the "License");
+    you may not use this file except in compliance with the License.
+    You may obtain a copy of the License at
+
+        http://www.apache.org/licenses/LICENSE-2.0
+
+    Unless required by applicable law or agreed to in writing, software
+    distributed under the License is distributed on an "AS IS" BASIS,
+    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+    See the License for the specific language governing permissions and
+    limitations under the License.
+*/
+
+extern crate griddb_sys as ffi;
+
+use std::ffi::c_void;
+use std::fmt;
+
+// One entry of the error stack kept by the C client
+#[derive(Debug, Clone)]
+pub struct ErrorStackEntry {
+    pub code: i32,
+    pub name: String,
+    pub message: String,
+    pub location: String,
+    pub parameters: Vec<(String, String)>,
+}
+
+// Class GridDbError
+#[derive(Debug, Clone)]
+pub struct GridDbError {
+    pub code: i32,
+    // stack[0] is the most recent error
+    pub stack: Vec<ErrorStackEntry>,
+}
+
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+impl GridDbError {
+    // Error detected by the Rust client itself, before calling the C API
+    pub fn new(code: i32, message: &str) -> GridDbError {
+        GridDbError {
+            code,
+            stack: vec![ErrorStackEntry {
+                code,
+                name: String::new(),
+                message: message.to_string(),
+                location: String::new(),
+                parameters: Vec::new(),
+            }],
+        }
+    }
+
+    // Collect the error stack held by the C resource (factory, store, container,
+    // query, row set, ...) on which the call returning `code` was made
+    pub fn from_resource(code: i32, resource: *mut c_void) -> GridDbError {
+        let mut stack: Vec<ErrorStackEntry> = Vec::new();
+        if !resource.is_null() {
+            let stack_size;
+            unsafe {
+                stack_size = ffi::gsGetErrorStackSize(resource);
+            }
+            for index in 0..stack_size {
+                let entry_code;
+                let parameter_count;
+                unsafe {
+                    entry_code = ffi::gsGetErrorCode(resource, index);
+                    parameter_count = ffi::gsGetErrorParameterCount(resource, index);
+                }
+                let mut parameters = Vec::new();
+                for param in 0..parameter_count {
+                    parameters.push((
+                        format_error_text(|buf, size| unsafe {
+                            ffi::gsFormatErrorParameterName(resource, index, param, buf, size)
+                        }),
+                        format_error_text(|buf, size| unsafe {
+                            ffi::gsFormatErrorParameterValue(resource, index, param, buf, size)
+                        }),
+                    ));
+                }
+                stack.push(ErrorStackEntry {
+                    code: entry_code,
+                    name: format_error_text(|buf, size| unsafe {
+                        ffi::gsFormatErrorName(resource, index, buf, size)
+                    }),
+                    message: format_error_text(|buf, size| unsafe {
+                        ffi::gsFormatErrorMessage(resource, index, buf, size)
+                    }),
+                    location: format_error_text(|buf, size| unsafe {
+                        ffi::gsFormatErrorLocation(resource, index, buf, size)
+                    }),
+                    parameters,
+                });
+            }
+        }
+        GridDbError { code, stack }
+    }
+
+    // Convert a C API result code into Result
+    pub fn check(ret: i32, resource: *mut c_void) -> Result<(), GridDbError> {
+        if ret == ffi::GS_RESULT_OK as i32 {
+            Ok(())
+        } else {
+            Err(GridDbError::from_resource(ret, resource))
+        }
+    }
+
+    // Push a message in front of the stack, keeping the code and the C client entries
+    pub fn with_context(mut self, context: &str) -> GridDbError {
+        let message = if self.message().is_empty() {
+            context.to_string()
+        } else {
+            format!("{}: {}", context, self.message())
+        };
+        self.stack.insert(
+            0,
+            ErrorStackEntry {
+                code: self.code,
+                name: String::new(),
+                message,
+                location: String::new(),
+                parameters: Vec::new(),
+            },
+        );
+        self
+    }
+
+    // Error which may not occur again when the operation is retried
+    pub fn is_recoverable(&self) -> bool {
+        let result;
+        unsafe {
+            result = ffi::gsIsRecoverableError(self.code);
+        }
+        result == ffi::GS_TRUE as i8
+    }
+
+    pub fn is_timeout(&self) -> bool {
+        let result;
+        unsafe {
+            result = ffi::gsIsTimeoutError(self.code);
+        }
+        result == ffi::GS_TRUE as i8
+    }
+
+    pub fn name(&self) -> &str {
+        self.stack.first().map_or("", |entry| entry.name.as_str())
+    }
+
+    pub fn message(&self) -> &str {
+        self.stack
+            .first()
+            .map_or("", |entry| entry.message.as_str())
+    }
+
+    pub fn location(&self) -> &str {
+        self.stack
+            .first()
+            .map_or("", |entry| entry.location.as_str())
+    }
+
+    pub fn parameters(&self) -> &[(String, String)] {
+        self.stack
+            .first()
+            .map_or(&[], |entry| entry.parameters.as_slice())
+    }
+}
+
+impl fmt::Display for GridDbError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.message().is_empty() {
+            write!(f, "GridDB error code: {}", self.code)
+        } else {
+            write!(f, "{}", self.message())
+        }
+    }
+}
+
+impl std::error::Error for GridDbError {}
+
+// Call a gsFormatError* function twice: once for the size, once for the text
+fn format_error_text<F>(format: F) -> String
+where
+    F: Fn(*mut ffi::GSChar, ffi::size_t) -> ffi::size_t,
+{
+    let size = format(std::ptr::null_mut(), 0) as usize;
+    if size == 0 {
+        return String::new();
+    }
+    let mut buf: Vec<u8> = vec![0; size + 1];
+    format(
+        buf.as_mut_ptr() as *mut ffi::GSChar,
+        buf.len() as ffi::size_t,
+    );
+    let end = buf.iter().position(|&c| c == 0).unwrap_or(buf.len());
+    String::from_utf8_lossy(&buf[..end]).into_owned()
+}
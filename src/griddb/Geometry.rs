@@ -0,0 +1,592 @@
+/*
+    Copyright (c) 2022 TOSHIBA Digital Solutions Corporation.
+
+    Licensed under the Apache License, Version 2.0 (the "License");
+    you may not use this file except in compliance with the License.
+    You may obtain a copy of the License at
+
+        http://www.apache.org/licenses/LICENSE-2.0
+
+    Unless required by applicable law or agreed to in writing, software
+    distributed under the License is distributed on an "AS IS" BASIS,
+    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+    See the License for the specific language governing permissions and
+    limitations under the License.
+*/
+
+use crate::griddb::Const::*;
+use crate::griddb::Error::*;
+use std::fmt;
+use std::str::FromStr;
+
+// Number of coefficients of QUADRATICSURFACE: 3x3 matrix, 3 linear terms, 1 constant
+pub const QUADRATIC_SURFACE_SIZE: usize = 13;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Coordinate {
+    pub x: f64,
+    pub y: f64,
+    pub z: Option<f64>,
+}
+
+impl Coordinate {
+    pub fn new(x: f64, y: f64) -> Coordinate {
+        Coordinate { x, y, z: None }
+    }
+
+    pub fn new_3d(x: f64, y: f64, z: f64) -> Coordinate {
+        Coordinate { x, y, z: Some(z) }
+    }
+}
+
+// GEOMETRY column value, converted from/to WKT of GridDB
+#[derive(Debug, Clone, PartialEq)]
+pub enum Geometry {
+    // None is POINT(EMPTY)
+    Point(Option<Coordinate>),
+    // Empty vector is LINESTRING(EMPTY)
+    LineString(Vec<Coordinate>),
+    // First ring is the exterior, the others are holes. Empty vector is POLYGON(EMPTY)
+    Polygon(Vec<Vec<Coordinate>>),
+    QuadraticSurface([f64; QUADRATIC_SURFACE_SIZE]),
+    // WKT read from the server that the parser does not handle, e.g.
+    // POLYHEDRALSURFACE. Kept as is so that the row can still be read
+    Other(String),
+}
+
+impl Geometry {
+    pub fn validate(&self) -> Result<(), GridDbError> {
+        match self {
+            Geometry::Point(point) => validate_coordinates(point.iter()),
+            Geometry::LineString(points) => {
+                if points.len() == 1 {
+                    return Err(geometry_error("LINESTRING needs at least 2 points"));
+                }
+                validate_coordinates(points.iter())
+            }
+            Geometry::Polygon(rings) => {
+                for ring in rings {
+                    if ring.len() < 4 {
+                        return Err(geometry_error("POLYGON ring needs at least 4 points"));
+                    }
+                    if ring.first() != ring.last() {
+                        return Err(geometry_error("POLYGON ring is not closed"));
+                    }
+                }
+                validate_coordinates(rings.iter().flatten())
+            }
+            Geometry::QuadraticSurface(coefficients) => {
+                if coefficients.iter().all(|value| value.is_finite()) {
+                    Ok(())
+                } else {
+                    Err(geometry_error("QUADRATICSURFACE coefficient is not finite"))
+                }
+            }
+            Geometry::Other(_) => Ok(()),
+        }
+    }
+
+    // Well-Known Text of the geometry
+    pub fn to_wkt(&self) -> String {
+        self.to_string()
+    }
+
+    // Geometry of a GEOMETRY column, Other when the WKT cannot be parsed
+    pub(crate) fn from_server_wkt(wkt: String) -> Geometry {
+        wkt.parse().unwrap_or(Geometry::Other(wkt))
+    }
+}
+
+impl fmt::Display for Geometry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Geometry::Point(None) => write!(f, "POINT(EMPTY)"),
+            Geometry::Point(Some(point)) => write!(f, "POINT({})", format_coordinate(point)),
+            Geometry::LineString(points) if points.is_empty() => write!(f, "LINESTRING(EMPTY)"),
+            Geometry::LineString(points) => write!(f, "LINESTRING({})", format_ring(points)),
+            Geometry::Polygon(rings) if rings.is_empty() => write!(f, "POLYGON(EMPTY)"),
+            Geometry::Polygon(rings) => {
+                let rings: Vec<String> = rings
+                    .iter()
+                    .map(|ring| format!("({})", format_ring(ring)))
+                    .collect();
+                write!(f, "POLYGON({})", rings.join(", "))
+            }
+            Geometry::QuadraticSurface(coefficients) => {
+                let coefficients: Vec<String> =
+                    coefficients.iter().map(|value| value.to_string()).collect();
+                write!(f, "QUADRATICSURFACE({})", coefficients.join(" "))
+            }
+            Geometry::Other(wkt) => write!(f, "{}", wkt),
+        }
+    }
+}
+
+impl FromStr for Geometry {
+    type Err = GridDbError;
+
+    fn from_str(wkt: &str) -> Result<Self, Self::Err> {
+        let mut parser = WktParser::new(wkt)?;
+        let geometry = parser.parse_geometry()?;
+        if parser.pos != parser.tokens.len() {
+            return Err(geometry_error(&format!(
+                "Unexpected text after geometry: {}",
+                wkt
+            )));
+        }
+        geometry.validate()?;
+        Ok(geometry)
+    }
+}
+
+impl TryFrom<&str> for Geometry {
+    type Error = GridDbError;
+
+    fn try_from(wkt: &str) -> Result<Self, Self::Error> {
+        wkt.parse()
+    }
+}
+
+impl TryFrom<String> for Geometry {
+    type Error = GridDbError;
+
+    fn try_from(wkt: String) -> Result<Self, Self::Error> {
+        wkt.parse()
+    }
+}
+
+fn geometry_error(message: &str) -> GridDbError {
+    GridDbError::new(ERROR_CONVERT_DATA, message)
+}
+
+fn validate_coordinates<'a, I>(points: I) -> Result<(), GridDbError>
+where
+    I: Iterator<Item = &'a Coordinate>,
+{
+    let mut dimension = None;
+    for point in points {
+        let finite =
+            point.x.is_finite() && point.y.is_finite() && point.z.iter().all(|z| z.is_finite());
+        if !finite {
+            return Err(geometry_error("Geometry coordinate is not finite"));
+        }
+        if *dimension.get_or_insert(point.z.is_some()) != point.z.is_some() {
+            return Err(geometry_error("Geometry mixes 2D and 3D coordinates"));
+        }
+    }
+    Ok(())
+}
+
+fn format_coordinate(point: &Coordinate) -> String {
+    match point.z {
+        Some(z) => format!("{} {} {}", point.x, point.y, z),
+        None => format!("{} {}", point.x, point.y),
+    }
+}
+
+fn format_ring(points: &[Coordinate]) -> String {
+    let points: Vec<String> = points.iter().map(format_coordinate).collect();
+    points.join(", ")
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum WktToken {
+    Word(String),
+    Number(f64),
+    LeftParen,
+    RightParen,
+    Comma,
+}
+
+struct WktParser {
+    tokens: Vec<WktToken>,
+    pos: usize,
+}
+
+impl WktParser {
+    fn new(wkt: &str) -> Result<WktParser, GridDbError> {
+        let mut tokens = Vec::new();
+        let mut chars = wkt.chars().peekable();
+        while let Some(&c) = chars.peek() {
+            if c.is_whitespace() {
+                chars.next();
+            } else if c == '(' || c == ')' || c == ',' {
+                tokens.push(match c {
+                    '(' => WktToken::LeftParen,
+                    ')' => WktToken::RightParen,
+                    _ => WktToken::Comma,
+                });
+                chars.next();
+            } else if c.is_ascii_alphabetic() {
+                let mut word = String::new();
+                while let Some(&c) = chars.peek() {
+                    if !c.is_ascii_alphabetic() {
+                        break;
+                    }
+                    word.push(c.to_ascii_uppercase());
+                    chars.next();
+                }
+                tokens.push(WktToken::Word(word));
+            } else if c.is_ascii_digit() || c == '-' || c == '+' || c == '.' {
+                let mut number = String::new();
+                while let Some(&c) = chars.peek() {
+                    let sign_allowed = number.is_empty() || number.ends_with(['e', 'E']);
+                    if !(c.is_ascii_digit()
+                        || c == '.'
+                        || c == 'e'
+                        || c == 'E'
+                        || ((c == '-' || c == '+') && sign_allowed))
+                    {
+                        break;
+                    }
+                    number.push(c);
+                    chars.next();
+                }
+                // Numbers are separated by whitespace or a delimiter, "1-2" is not "1 -2"
+                if let Some(&c) = chars.peek() {
+                    if !(c.is_whitespace() || c == '(' || c == ')' || c == ',') {
+                        return Err(geometry_error(&format!(
+                            "Invalid number in WKT: {}{}",
+                            number, c
+                        )));
+                    }
+                }
+                let value = number
+                    .parse::<f64>()
+                    .map_err(|_| geometry_error(&format!("Invalid number in WKT: {}", number)))?;
+                tokens.push(WktToken::Number(value));
+            } else {
+                return Err(geometry_error(&format!("Invalid character in WKT: {}", c)));
+            }
+        }
+        Ok(WktParser { tokens, pos: 0 })
+    }
+
+    fn peek(&self) -> Option<&WktToken> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<WktToken> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn expect(&mut self, expected: WktToken) -> Result<(), GridDbError> {
+        match self.next() {
+            Some(ref token) if *token == expected => Ok(()),
+            token => Err(geometry_error(&format!(
+                "Expected {:?} in WKT but found {:?}",
+                expected, token
+            ))),
+        }
+    }
+
+    // Consume "EMPTY)" if it follows
+    fn parse_empty(&mut self) -> Result<bool, GridDbError> {
+        if self.peek() == Some(&WktToken::Word(String::from("EMPTY"))) {
+            self.pos += 1;
+            self.expect(WktToken::RightParen)?;
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    fn parse_geometry(&mut self) -> Result<Geometry, GridDbError> {
+        let tag = match self.next() {
+            Some(WktToken::Word(word)) => word,
+            token => {
+                return Err(geometry_error(&format!(
+                    "Expected geometry type in WKT but found {:?}",
+                    token
+                )))
+            }
+        };
+        self.expect(WktToken::LeftParen)?;
+        match tag.as_str() {
+            "POINT" => {
+                if self.parse_empty()? {
+                    return Ok(Geometry::Point(None));
+                }
+                let point = self.parse_coordinate()?;
+                self.expect(WktToken::RightParen)?;
+                Ok(Geometry::Point(Some(point)))
+            }
+            "LINESTRING" => {
+                if self.parse_empty()? {
+                    return Ok(Geometry::LineString(Vec::new()));
+                }
+                Ok(Geometry::LineString(self.parse_coordinate_list()?))
+            }
+            "POLYGON" => {
+                if self.parse_empty()? {
+                    return Ok(Geometry::Polygon(Vec::new()));
+                }
+                let mut rings = Vec::new();
+                loop {
+                    self.expect(WktToken::LeftParen)?;
+                    rings.push(self.parse_coordinate_list()?);
+                    match self.next() {
+                        Some(WktToken::Comma) => continue,
+                        Some(WktToken::RightParen) => break,
+                        token => {
+                            return Err(geometry_error(&format!(
+                                "Expected ',' or ')' in POLYGON but found {:?}",
+                                token
+                            )))
+                        }
+                    }
+                }
+                Ok(Geometry::Polygon(rings))
+            }
+            "QUADRATICSURFACE" => {
+                let mut coefficients = [0.0; QUADRATIC_SURFACE_SIZE];
+                for coefficient in coefficients.iter_mut() {
+                    *coefficient = self.parse_number()?;
+                }
+                self.expect(WktToken::RightParen)?;
+                Ok(Geometry::QuadraticSurface(coefficients))
+            }
+            _ => Err(geometry_error(&format!(
+                "Unsupported geometry type: {}",
+                tag
+            ))),
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<f64, GridDbError> {
+        match self.next() {
+            Some(WktToken::Number(value)) => Ok(value),
+            token => Err(geometry_error(&format!(
+                "Expected number in WKT but found {:?}",
+                token
+            ))),
+        }
+    }
+
+    fn parse_coordinate(&mut self) -> Result<Coordinate, GridDbError> {
+        let x = self.parse_number()?;
+        let y = self.parse_number()?;
+        if let Some(WktToken::Number(z)) = self.peek() {
+            let z = *z;
+            self.pos += 1;
+            return Ok(Coordinate::new_3d(x, y, z));
+        }
+        Ok(Coordinate::new(x, y))
+    }
+
+    // Coordinates separated by ',' until ')'
+    fn parse_coordinate_list(&mut self) -> Result<Vec<Coordinate>, GridDbError> {
+        let mut points = vec![self.parse_coordinate()?];
+        loop {
+            match self.next() {
+                Some(WktToken::Comma) => points.push(self.parse_coordinate()?),
+                Some(WktToken::RightParen) => return Ok(points),
+                token => {
+                    return Err(geometry_error(&format!(
+                        "Expected ',' or ')' in WKT but found {:?}",
+                        token
+                    )))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "geo-types")]
+mod geo_types_support {
+    use super::*;
+
+    fn to_geo_coordinate(point: &Coordinate) -> Result<geo_types::Coord<f64>, GridDbError> {
+        if point.z.is_some() {
+            return Err(geometry_error("geo-types does not support 3D coordinates"));
+        }
+        Ok(geo_types::coord! { x: point.x, y: point.y })
+    }
+
+    fn to_geo_line_string(
+        points: &[Coordinate],
+    ) -> Result<geo_types::LineString<f64>, GridDbError> {
+        let points: Result<Vec<_>, _> = points.iter().map(to_geo_coordinate).collect();
+        Ok(geo_types::LineString::new(points?))
+    }
+
+    fn from_geo_line_string(line: &geo_types::LineString<f64>) -> Vec<Coordinate> {
+        line.coords()
+            .map(|point| Coordinate::new(point.x, point.y))
+            .collect()
+    }
+
+    impl From<geo_types::Point<f64>> for Geometry {
+        fn from(point: geo_types::Point<f64>) -> Self {
+            Geometry::Point(Some(Coordinate::new(point.x(), point.y())))
+        }
+    }
+
+    impl From<geo_types::LineString<f64>> for Geometry {
+        fn from(line: geo_types::LineString<f64>) -> Self {
+            Geometry::LineString(from_geo_line_string(&line))
+        }
+    }
+
+    impl From<geo_types::Polygon<f64>> for Geometry {
+        fn from(polygon: geo_types::Polygon<f64>) -> Self {
+            let mut rings = vec![from_geo_line_string(polygon.exterior())];
+            rings.extend(polygon.interiors().iter().map(from_geo_line_string));
+            Geometry::Polygon(rings)
+        }
+    }
+
+    impl TryFrom<Geometry> for geo_types::Geometry<f64> {
+        type Error = GridDbError;
+
+        fn try_from(geometry: Geometry) -> Result<Self, Self::Error> {
+            match geometry {
+                Geometry::Point(Some(point)) => {
+                    Ok(geo_types::Point::from(to_geo_coordinate(&point)?).into())
+                }
+                Geometry::Point(None) => {
+                    Err(geometry_error("geo-types does not support POINT(EMPTY)"))
+                }
+                Geometry::LineString(points) => Ok(to_geo_line_string(&points)?.into()),
+                Geometry::Polygon(rings) => {
+                    let mut rings = rings.iter();
+                    let exterior = match rings.next() {
+                        Some(ring) => to_geo_line_string(ring)?,
+                        None => geo_types::LineString::new(Vec::new()),
+                    };
+                    let interiors: Result<Vec<_>, _> =
+                        rings.map(|ring| to_geo_line_string(ring)).collect();
+                    Ok(geo_types::Polygon::new(exterior, interiors?).into())
+                }
+                Geometry::QuadraticSurface(_) => Err(geometry_error(
+                    "geo-types does not support QUADRATICSURFACE",
+                )),
+                Geometry::Other(wkt) => Err(geometry_error(&format!(
+                    "geo-types does not support {}",
+                    wkt
+                ))),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(wkt: &str) -> Result<Geometry, GridDbError> {
+        wkt.parse()
+    }
+
+    #[test]
+    fn wkt_round_trip() {
+        for wkt in [
+            "POINT(1 2)",
+            "POINT(-1.5 2.25 3)",
+            "LINESTRING(0 0, 1 1, 2 0.5)",
+            "LINESTRING(0 0 1, 1 1 2)",
+            "POLYGON((0 0, 4 0, 4 4, 0 4, 0 0))",
+            "POLYGON((0 0, 4 0, 4 4, 0 4, 0 0), (1 1, 2 1, 2 2, 1 1))",
+            "QUADRATICSURFACE(1 0 0 0 1 0 0 0 -1 0 0 0 -2.5)",
+        ] {
+            assert_eq!(parse(wkt).unwrap().to_wkt(), wkt);
+        }
+    }
+
+    #[test]
+    fn wkt_empty_geometries() {
+        assert_eq!(parse("POINT(EMPTY)").unwrap(), Geometry::Point(None));
+        assert_eq!(
+            parse("LINESTRING(EMPTY)").unwrap(),
+            Geometry::LineString(Vec::new())
+        );
+        assert_eq!(
+            parse("POLYGON(EMPTY)").unwrap(),
+            Geometry::Polygon(Vec::new())
+        );
+        for wkt in ["POINT(EMPTY)", "LINESTRING(EMPTY)", "POLYGON(EMPTY)"] {
+            assert_eq!(parse(wkt).unwrap().to_wkt(), wkt);
+        }
+    }
+
+    #[test]
+    fn wkt_polygon_with_holes() {
+        let geometry = parse(
+            "polygon ( (0 0,4 0,4 4,0 4,0 0) , (1 1,2 1,2 2,1 1) , (3 3,3.5 3,3.5 3.5,3 3) )",
+        )
+        .unwrap();
+        match geometry {
+            Geometry::Polygon(rings) => {
+                assert_eq!(rings.len(), 3);
+                assert_eq!(rings[0].len(), 5);
+                assert_eq!(rings[1][1], Coordinate::new(2.0, 1.0));
+                assert_eq!(rings[2][2], Coordinate::new(3.5, 3.5));
+            }
+            geometry => panic!("Expected POLYGON but found {:?}", geometry),
+        }
+    }
+
+    #[test]
+    fn wkt_numbers() {
+        assert_eq!(
+            parse("POINT(+1e2 -2.5E-1 .5)").unwrap(),
+            Geometry::Point(Some(Coordinate::new_3d(100.0, -0.25, 0.5)))
+        );
+    }
+
+    #[test]
+    fn wkt_malformed_input() {
+        for wkt in [
+            "",
+            "POINT",
+            "POINT()",
+            "POINT(1)",
+            "POINT(1 2",
+            "POINT(1 2) x",
+            "POINT(1 2 3 4)",
+            "POINT(EMPTY",
+            "POINT(1 2;)",
+            "POINT(1..2 3)",
+            "POINT(1-2)",
+            "POINT(1 2-3)",
+            "POINT(1.5.2 3)",
+            "CIRCLE(1 2)",
+            "LINESTRING(0 0)",
+            "LINESTRING(0 0, 1 1 1)",
+            "LINESTRING(0 0,, 1 1)",
+            "POLYGON(0 0, 1 0, 1 1, 0 0)",
+            "POLYGON((0 0, 1 0, 0 0))",
+            "POLYGON((0 0, 1 0, 1 1, 0 1))",
+            "POLYGON((0 0, 1 0, 1 1, 0 0) (0 0, 1 0, 1 1, 0 0))",
+            "QUADRATICSURFACE(1 2 3)",
+        ] {
+            let error = parse(wkt).expect_err(wkt);
+            assert_eq!(error.code, ERROR_CONVERT_DATA, "{}", wkt);
+        }
+    }
+
+    #[test]
+    fn unknown_wkt_from_server_is_kept() {
+        let wkt = "POLYHEDRALSURFACE(((0 0 0, 0 1 0, 1 1 0, 0 0 0)))";
+        assert!(parse(wkt).is_err());
+        let geometry = Geometry::from_server_wkt(wkt.to_string());
+        assert_eq!(geometry, Geometry::Other(wkt.to_string()));
+        assert_eq!(geometry.to_wkt(), wkt);
+        assert!(geometry.validate().is_ok());
+        assert_eq!(
+            Geometry::from_server_wkt("POINT(1 2)".to_string()),
+            Geometry::Point(Some(Coordinate::new(1.0, 2.0)))
+        );
+    }
+
+    #[test]
+    fn validate_rejects_non_finite_coordinates() {
+        assert!(Geometry::Point(Some(Coordinate::new(f64::NAN, 0.0)))
+            .validate()
+            .is_err());
+        let mut coefficients = [0.0; QUADRATIC_SURFACE_SIZE];
+        coefficients[3] = f64::INFINITY;
+        assert!(Geometry::QuadraticSurface(coefficients).validate().is_err());
+    }
+}
@@ -0,0 +1,118 @@
+/*
+    Copyright (c) 2022 TOSHIBA Digital Solutions Corporation.
+
+    Licensed under the Apache License, Version 2.0 (the "License");
+    you may not use this file except in compliance with the License.
+    You may obtain a copy of the License at
+
+        http://www.apache.org/licenses/LICENSE-2.0
+
+    Unless required by applicable law or agreed to in writing, software
+    distributed under the License is distributed on an "AS IS" BASIS,
+    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+    See the License for the specific language governing permissions and
+    limitations under the License.
+*/
+
+use crate::griddb::Const::*;
+use crate::griddb::ContainerInfo::*;
+use crate::griddb::Error::*;
+use crate::griddb::Type::*;
+use crate::griddb::Value::*;
+
+// #[derive(GridRow)] with #[griddb(key)], #[griddb(nullable)] and #[griddb(rename = "...")]
+pub use griddb_derive::GridRow;
+
+// Struct mapped to a row, usually implemented with #[derive(GridRow)]
+pub trait GridRow: Sized {
+    // Whether the first column is the row key
+    const ROW_KEY: bool;
+
+    // Type of the row key field, () when there is no row key
+    type Key;
+
+    // Name, type and nullability of every column, in row order
+    fn columns() -> Vec<(&'static str, Type, TypeOption)>;
+
+    // Values for Container::put
+    fn to_row(&self) -> Vec<Value>;
+
+    // Build from the values returned by Container::get or RowSet::next
+    fn from_row(row: Vec<Value>) -> Result<Self, GridDbError>;
+
+    fn container_info(name: &str, container_type: ContainerType) -> ContainerInfo {
+        ContainerInfo::with_options(name, Self::columns(), container_type, Self::ROW_KEY)
+    }
+}
+
+// Column type of a Rust field type. Option<T> is a nullable column of T
+pub trait GridColumn {
+    const TYPE: Type;
+    const NULLABLE: bool = false;
+}
+
+macro_rules! impl_grid_column {
+    ($($t:ty => $column_type:ident),*) => {
+        $(
+            impl GridColumn for $t {
+                const TYPE: Type = Type::$column_type;
+            }
+        )*
+    };
+}
+
+impl_grid_column!(
+    String => String,
+    bool => Bool,
+    i8 => Byte,
+    i16 => Short,
+    i32 => Integer,
+    i64 => Long,
+    f32 => Float,
+    f64 => Double,
+    Timestamp => Timestamp,
+    Geometry => Geometry,
+    Vec<u8> => Blob,
+    Vec<String> => StringArray,
+    Vec<bool> => BoolArray,
+    Vec<i8> => ByteArray,
+    Vec<i16> => ShortArray,
+    Vec<i32> => IntegerArray,
+    Vec<i64> => LongArray,
+    Vec<f32> => FloatArray,
+    Vec<f64> => DoubleArray,
+    Vec<Timestamp> => TimestampArray
+);
+
+impl<T: GridColumn> GridColumn for Option<T> {
+    const TYPE: Type = T::TYPE;
+    const NULLABLE: bool = true;
+}
+
+// Used by #[derive(GridRow)]
+pub fn check_column_count(row: &[Value], count: usize) -> Result<(), GridDbError> {
+    if row.len() != count {
+        return Err(GridDbError::new(
+            ERROR_CONVERT_DATA,
+            &format!(
+                "Row has {} values but {} columns are expected",
+                row.len(),
+                count
+            ),
+        ));
+    }
+    Ok(())
+}
+
+// Used by #[derive(GridRow)]
+pub fn column_value<T>(value: Value, column: &str) -> Result<T, GridDbError>
+where
+    T: TryFrom<Value, Error = GridDbError>,
+{
+    T::try_from(value).map_err(|error| {
+        GridDbError::new(
+            ERROR_CONVERT_DATA,
+            &format!("Column {}: {}", column, error.message()),
+        )
+    })
+}
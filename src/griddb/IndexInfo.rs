@@ -0,0 +1,151 @@
+/*
+    Copyright (c) 2022 TOSHIBA Digital Solutions Corporation.
+
+    Licensed under the Apache License, Version 2.0 (the "License");
+    you may not use this file except in compliance with the License.
+    You may obtain a copy of the License at
+
+        http://www.apache.org/licenses/LICENSE-2.0
+
+    Unless required by applicable law or agreed to in writing, software
+    distributed under the License is distributed on an "AS IS" BASIS,
+    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+    See the License for the specific language governing permissions and
+    limitations under the License.
+*/
+
+extern crate griddb_sys as ffi;
+
+use crate::griddb::Type::*;
+use crate::num_to_enum;
+use std::ffi::CStr;
+use std::ffi::CString;
+use std::ptr;
+
+// Class IndexInfo: index on one or more columns, given by name or position
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexInfo {
+    // None for an index without name
+    pub name: Option<String>,
+    pub index_type: IndexType,
+    pub column_list: Vec<i32>,
+    pub column_name_list: Vec<String>,
+}
+
+impl IndexInfo {
+    pub fn new(column_name_list: &[&str], index_type: IndexType) -> IndexInfo {
+        IndexInfo {
+            name: None,
+            index_type,
+            column_list: vec![],
+            column_name_list: column_name_list
+                .iter()
+                .map(|name| name.to_string())
+                .collect(),
+        }
+    }
+
+    pub fn with_name(name: &str, column_name_list: &[&str], index_type: IndexType) -> IndexInfo {
+        IndexInfo {
+            name: Some(name.to_string()),
+            ..IndexInfo::new(column_name_list, index_type)
+        }
+    }
+
+    // Read an index returned by the C API
+    pub fn from_gs(info: &ffi::GSIndexInfo) -> IndexInfo {
+        let name;
+        let mut column_list = vec![];
+        let mut column_name_list = vec![];
+        unsafe {
+            name = if info.name.is_null() {
+                None
+            } else {
+                Some(CStr::from_ptr(info.name).to_string_lossy().into_owned())
+            };
+            if info.columnCount > 0 {
+                column_list =
+                    std::slice::from_raw_parts(info.columnList, info.columnCount as usize).to_vec();
+            } else if info.column >= 0 {
+                column_list.push(info.column);
+            }
+            if info.columnNameCount > 0 {
+                for column_name in
+                    std::slice::from_raw_parts(info.columnNameList, info.columnNameCount as usize)
+                {
+                    column_name_list
+                        .push(CStr::from_ptr(*column_name).to_string_lossy().into_owned());
+                }
+            } else if !info.columnName.is_null() {
+                column_name_list.push(
+                    CStr::from_ptr(info.columnName)
+                        .to_string_lossy()
+                        .into_owned(),
+                );
+            }
+        }
+        IndexInfo {
+            name,
+            index_type: num_to_enum!(
+                info.type_ => IndexType<i32> { Default, Tree, Hash, Spatial };
+                IndexType::Default
+            ),
+            column_list,
+            column_name_list,
+        }
+    }
+}
+
+// GSIndexInfo with the strings it points to
+pub struct GsIndexInfo {
+    _name: Option<CString>,
+    _index_type: ffi::GSIndexTypeFlags,
+    _column_list: Vec<i32>,
+    _column_names: Vec<CString>,
+    _column_name_ptrs: Vec<*const ffi::GSChar>,
+}
+
+impl GsIndexInfo {
+    pub fn new(info: &IndexInfo) -> GsIndexInfo {
+        let column_names: Vec<CString> = info
+            .column_name_list
+            .iter()
+            .map(|name| CString::new(name.as_str()).expect("Error convert String to CString"))
+            .collect();
+        GsIndexInfo {
+            _name: info
+                .name
+                .as_ref()
+                .map(|name| CString::new(name.as_str()).expect("Error convert String to CString")),
+            _index_type: info.index_type as ffi::GSIndexTypeFlags,
+            _column_list: info.column_list.clone(),
+            _column_name_ptrs: column_names.iter().map(|name| name.as_ptr()).collect(),
+            _column_names: column_names,
+        }
+    }
+
+    // Valid while self is alive
+    pub fn unwrap(&self) -> ffi::GSIndexInfo {
+        ffi::GSIndexInfo {
+            name: self
+                ._name
+                .as_ref()
+                .map_or(ptr::null(), |name| name.as_ptr()),
+            type_: self._index_type,
+            column: -1,
+            columnName: ptr::null(),
+            columnCount: self._column_list.len() as ffi::size_t,
+            columnList: if self._column_list.is_empty() {
+                ptr::null()
+            } else {
+                self._column_list.as_ptr()
+            },
+            columnNameCount: self._column_name_ptrs.len() as ffi::size_t,
+            columnNameList: if self._column_name_ptrs.is_empty() {
+                ptr::null()
+            } else {
+                self._column_name_ptrs.as_ptr()
+            },
+        }
+    }
+}
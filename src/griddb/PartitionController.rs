@@ -0,0 +1,192 @@
+/*
+    Copyright (c) 2022 TOSHIBA Digital Solutions Corporation.
+
+    Licensed under the Apache License, Version 2.0 (the "License");
+    you may not use this file except in compliance with the License.
+    You may obtain a copy of the License at
+
+        http://www.apache.org/licenses/LICENSE-2.0
+
+    Unless required by applicable law or agreed to in writing, software
+    distributed under the License is distributed on an "AS IS" BASIS,
+    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+    See the License for the specific language governing permissions and
+    limitations under the License.
+*/
+
+extern crate griddb_sys as ffi;
+
+use crate::griddb::Error::*;
+use std::ffi::c_void;
+use std::ffi::CStr;
+use std::ffi::CString;
+use std::ptr;
+
+// Class PartitionController: partitions of the cluster and the containers
+// and hosts of each of them. Partitions are numbered from 0 to
+// get_partition_count() - 1
+pub struct PartitionController {
+    _ptr: *mut ffi::GSPartitionController,
+}
+
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+impl PartitionController {
+    pub fn new(controller: *mut ffi::GSPartitionController) -> PartitionController {
+        PartitionController { _ptr: controller }
+    }
+
+    fn check(&self, ret: i32) -> Result<(), GridDbError> {
+        GridDbError::check(ret, self._ptr as *mut c_void)
+    }
+
+    pub fn get_partition_count(&self) -> Result<i32, GridDbError> {
+        let mut count = 0;
+        let ret;
+        unsafe {
+            ret = ffi::gsGetPartitionCount(self._ptr, &mut count);
+        }
+        self.check(ret)?;
+        Ok(count)
+    }
+
+    pub fn get_container_count(&self, partition_index: i32) -> Result<i64, GridDbError> {
+        let mut count = 0;
+        let ret;
+        unsafe {
+            ret = ffi::gsGetPartitionContainerCount(self._ptr, partition_index, &mut count);
+        }
+        self.check(ret)?;
+        Ok(count)
+    }
+
+    // Names of the containers of a partition from start, at most limit of them.
+    // All remaining names when limit is None
+    pub fn get_container_names(
+        &self,
+        partition_index: i32,
+        start: i64,
+        limit: Option<i64>,
+    ) -> Result<Vec<String>, GridDbError> {
+        let mut name_list: *const *const ffi::GSChar = ptr::null();
+        let mut size: ffi::size_t = 0;
+        let ret;
+        unsafe {
+            ret = ffi::gsGetPartitionContainerNames(
+                self._ptr,
+                partition_index,
+                start,
+                limit
+                    .as_ref()
+                    .map_or(ptr::null(), |limit| limit as *const i64),
+                &mut name_list,
+                &mut size,
+            );
+        }
+        self.check(ret)?;
+        Ok(to_string_list(name_list, size))
+    }
+
+    // Addresses of every host holding the partition
+    pub fn get_hosts(&self, partition_index: i32) -> Result<Vec<String>, GridDbError> {
+        let mut address_list: *const *const ffi::GSChar = ptr::null();
+        let mut size: ffi::size_t = 0;
+        let ret;
+        unsafe {
+            ret =
+                ffi::gsGetPartitionHosts(self._ptr, partition_index, &mut address_list, &mut size);
+        }
+        self.check(ret)?;
+        Ok(to_string_list(address_list, size))
+    }
+
+    // None when the partition has no owner
+    pub fn get_owner_host(&self, partition_index: i32) -> Result<Option<String>, GridDbError> {
+        let mut address: *const ffi::GSChar = ptr::null();
+        let ret;
+        unsafe {
+            ret = ffi::gsGetPartitionOwnerHost(self._ptr, partition_index, &mut address);
+        }
+        self.check(ret)?;
+        if address.is_null() {
+            return Ok(None);
+        }
+        let owner;
+        unsafe {
+            owner = CStr::from_ptr(address).to_string_lossy().into_owned();
+        }
+        Ok(Some(owner))
+    }
+
+    pub fn get_backup_hosts(&self, partition_index: i32) -> Result<Vec<String>, GridDbError> {
+        let mut address_list: *const *const ffi::GSChar = ptr::null();
+        let mut size: ffi::size_t = 0;
+        let ret;
+        unsafe {
+            ret = ffi::gsGetPartitionBackupHosts(
+                self._ptr,
+                partition_index,
+                &mut address_list,
+                &mut size,
+            );
+        }
+        self.check(ret)?;
+        Ok(to_string_list(address_list, size))
+    }
+
+    // Host this client connects to first for the partition, None resets it
+    pub fn assign_preferable_host(
+        &self,
+        partition_index: i32,
+        host: Option<&str>,
+    ) -> Result<(), GridDbError> {
+        let host = host.map(|host| CString::new(host).expect("Error convert String to CString"));
+        let ret;
+        unsafe {
+            ret = ffi::gsAssignPartitionPreferableHost(
+                self._ptr,
+                partition_index,
+                host.as_ref().map_or(ptr::null(), |host| host.as_ptr()),
+            );
+        }
+        self.check(ret)
+    }
+
+    pub fn partition_index_of_container(&self, name: &str) -> Result<i32, GridDbError> {
+        let container_name = CString::new(name).expect("Error convert String to CString");
+        let mut partition_index = 0;
+        let ret;
+        unsafe {
+            ret = ffi::gsGetPartitionIndexOfContainer(
+                self._ptr,
+                container_name.as_ptr(),
+                &mut partition_index,
+            );
+        }
+        self.check(ret)?;
+        Ok(partition_index)
+    }
+}
+
+// Copy a string list owned by the controller, valid only until its next call
+fn to_string_list(list: *const *const ffi::GSChar, size: ffi::size_t) -> Vec<String> {
+    if size == 0 {
+        return vec![];
+    }
+    let string_list;
+    unsafe {
+        string_list = std::slice::from_raw_parts(list, size as usize)
+            .iter()
+            .map(|value| CStr::from_ptr(*value).to_string_lossy().into_owned())
+            .collect();
+    }
+    string_list
+}
+
+// Destructor
+impl Drop for PartitionController {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::gsClosePartitionController(&mut self._ptr);
+        }
+    }
+}
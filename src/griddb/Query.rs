@@ -17,88 +17,157 @@
 extern crate griddb_sys as ffi;
 
 use crate::griddb::Const::*;
+use crate::griddb::Error::*;
+use crate::griddb::RetryPolicy::*;
 use crate::griddb::RowSet::*;
 use crate::griddb::Type::*;
+use crate::griddb::Util::*;
 
+use std::cell::Cell;
 use std::collections::HashMap;
+use std::ffi::c_void;
 use std::ptr;
+use std::rc::Rc;
 
 // Class Query
 pub struct Query {
     _ptr: *mut ffi::GSQuery,
     _row: *mut ffi::GSRow,
+    _name_list: Vec<String>,
     _type_list: Vec<Type>,
+    _nullable_list: Vec<bool>,
+    // Auto-commit of the container, checked by fetch_for_update()
+    _auto_commit: Rc<Cell<bool>>,
+    _retry: Rc<RetryHandler>,
 }
 impl Query {
     const LIMIT_KEY: &'static str = "limit";
     const PARTIAL_KEY: &'static str = "partial";
-    pub fn new(query: *mut ffi::GSQuery, row: *mut ffi::GSRow, type_list: Vec<Type>) -> Query {
+    pub fn new(
+        query: *mut ffi::GSQuery,
+        row: *mut ffi::GSRow,
+        name_list: Vec<String>,
+        type_list: Vec<Type>,
+        nullable_list: Vec<bool>,
+        auto_commit: Rc<Cell<bool>>,
+        retry: Rc<RetryHandler>,
+    ) -> Query {
         Query {
             _ptr: query,
             _row: row,
+            _name_list: name_list,
             _type_list: type_list,
+            _nullable_list: nullable_list,
+            _auto_commit: auto_commit,
+            _retry: retry,
         }
     }
 
-    pub fn fetch(&self) -> Result<RowSet, i32> {
+    pub(crate) fn as_ptr(&self) -> *mut ffi::GSQuery {
+        self._ptr
+    }
+
+    fn check(&self, ret: i32) -> Result<(), GridDbError> {
+        GridDbError::check(ret, self._ptr as *mut c_void)
+    }
+
+    pub fn fetch(&self) -> Result<RowSet, GridDbError> {
+        self._retry
+            .run(OperationKind::Fetch, || self.fetch_rows(false))
+    }
+
+    // Fetch and lock the rows, so that they can be changed with
+    // RowSet::update_current() and RowSet::delete_current().
+    // Auto-commit of the container must be disabled
+    pub fn fetch_for_update(&self) -> Result<RowSet, GridDbError> {
+        Util::check_manual_commit(self._auto_commit.get(), "fetch_for_update")?;
+        self.fetch_rows(true)
+    }
+
+    fn fetch_rows(&self, for_update: bool) -> Result<RowSet, GridDbError> {
         let mut p_row_set: *mut ffi::GSRowSet = ptr::null_mut();
         let ret;
         unsafe {
-            ret = ffi::gsFetch(self._ptr, ffi::GS_FALSE as i8, &mut p_row_set);
+            ret = ffi::gsFetch(self._ptr, for_update as i8, &mut p_row_set);
+        }
+        self.check(ret)?;
+        Ok(RowSet::new(
+            p_row_set,
+            self._row,
+            self._name_list.clone(),
+            self._type_list.clone(),
+            self._nullable_list.clone(),
+        ))
+    }
+
+    // Fetch the plan of an EXPLAIN or EXPLAIN ANALYZE query as text,
+    // one entry per line indented by its depth
+    pub fn explain(&self) -> Result<String, GridDbError> {
+        let row_set = self.fetch()?;
+        if row_set.rowset_type != RowSetType::QueryAnalysis {
+            return Err(GridDbError::new(
+                ERROR_INVALID_STATE,
+                "Query is not an EXPLAIN or EXPLAIN ANALYZE query",
+            ));
         }
-        if ret == ffi::GS_RESULT_OK as i32 {
-            Ok(RowSet::new(p_row_set, self._row, self._type_list.clone()))
-        } else {
-            Err(ret)
+        let mut plan = String::new();
+        while row_set.has_next() {
+            let entry = row_set.next_query_analysis()?;
+            let indent = "  ".repeat(entry.depth.max(0) as usize);
+            plan.push_str(&format!("{}{}\n", indent, entry));
         }
+        Ok(plan)
     }
 
-    pub fn get_row_set(&self) -> Result<RowSet, i32> {
+    // Row set of the last fetch, including one done by Store::fetch_all()
+    pub fn get_row_set(&self) -> Result<RowSet, GridDbError> {
         let mut p_row_set: *mut ffi::GSRowSet = ptr::null_mut();
         let ret;
         unsafe {
             ret = ffi::gsGetRowSet(self._ptr, &mut p_row_set);
         }
-        if ret == ffi::GS_RESULT_OK as i32 {
-            Ok(RowSet::new(p_row_set, self._row, self._type_list.clone()))
-        } else {
-            Err(ret)
-        }
+        self.check(ret)?;
+        Ok(RowSet::new(
+            p_row_set,
+            self._row,
+            self._name_list.clone(),
+            self._type_list.clone(),
+            self._nullable_list.clone(),
+        ))
     }
 
-    pub fn set_fetch_options(&self, hashmap: HashMap<String, i32>) -> i32 {
+    pub fn set_fetch_options(&self, hashmap: HashMap<String, i32>) -> Result<(), GridDbError> {
         let mut limit: i32 = 0;
         let mut partial: bool = true;
         for (key, value) in &hashmap {
             match key.as_ref() {
                 Query::LIMIT_KEY => limit = *value,
-                Query::PARTIAL_KEY => {
-                    if *value != 0 {
-                        partial = true;
-                    } else {
-                        partial = false;
-                    }
-                }
+                Query::PARTIAL_KEY => partial = *value != 0,
                 _ => {
-                    return ERROR_CONVERT_DATA;
+                    return Err(GridDbError::new(
+                        ERROR_CONVERT_DATA,
+                        &format!("Unknown fetch option: {}", key),
+                    ));
                 }
             }
         }
 
-        let ret;
+        let mut ret;
         let limit_ptr: *const ::std::os::raw::c_void =
             &mut limit as *mut _ as *mut ::std::os::raw::c_void;
 
         let partial_ptr: *const ::std::os::raw::c_void =
             &mut partial as *mut _ as *mut ::std::os::raw::c_void;
         unsafe {
-            ffi::gsSetFetchOption(
+            ret = ffi::gsSetFetchOption(
                 self._ptr,
                 ffi::GSFetchOptionTag_GS_FETCH_LIMIT as i32,
                 limit_ptr,
                 ffi::GSTypeTag_GS_TYPE_INTEGER,
             );
-
+        }
+        self.check(ret)?;
+        unsafe {
             ret = ffi::gsSetFetchOption(
                 self._ptr,
                 ffi::GSFetchOptionTag_GS_FETCH_PARTIAL_EXECUTION as i32,
@@ -106,7 +175,7 @@ impl Query {
                 ffi::GSTypeTag_GS_TYPE_BOOL,
             );
         }
-        ret
+        self.check(ret)
     }
 }
 
@@ -0,0 +1,57 @@
+/*
+    Copyright (c) 2022 TOSHIBA Digital Solutions Corporation.
+
+    Licensed under the Apache License, Version 2.0 (the "License");
+    you may not use this file except in compliance with the License.
+    You may obtain a copy of the License at
+
+        http://www.apache.org/licenses/LICENSE-2.0
+
+    Unless required by applicable law or agreed to in writing, software
+    distributed under the License is distributed on an "AS IS" BASIS,
+    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+    See the License for the specific language governing permissions and
+    limitations under the License.
+*/
+
+extern crate griddb_sys as ffi;
+
+use crate::griddb::Util::*;
+use std::fmt;
+
+// Class QueryAnalysisEntry: one step of the plan returned by EXPLAIN or
+// EXPLAIN ANALYZE. Entries form a tree given by their depth
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryAnalysisEntry {
+    pub id: i32,
+    pub depth: i32,
+    pub entry_type: String,
+    pub value_type: String,
+    pub value: String,
+    pub statement: String,
+}
+
+impl QueryAnalysisEntry {
+    // Copy an entry returned by the C API, a null string is empty
+    pub fn from_gs(entry: &ffi::GSQueryAnalysisEntry) -> QueryAnalysisEntry {
+        QueryAnalysisEntry {
+            id: entry.id,
+            depth: entry.depth,
+            entry_type: Util::to_string(entry.type_).unwrap_or_default(),
+            value_type: Util::to_string(entry.valueType).unwrap_or_default(),
+            value: Util::to_string(entry.value).unwrap_or_default(),
+            statement: Util::to_string(entry.statement).unwrap_or_default(),
+        }
+    }
+}
+
+// Type followed by the value when there is one
+impl fmt::Display for QueryAnalysisEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.value.is_empty() {
+            write!(f, "{}", self.entry_type)
+        } else {
+            write!(f, "{} {}", self.entry_type, self.value)
+        }
+    }
+}
@@ -0,0 +1,209 @@
+/*
+    Copyright (c) 2022 TOSHIBA Digital Solutions Corporation.
+
+    Licensed under the Apache License, Version 2.0 (the "License");
+    you may not use this file except in compliance with the License.
+    You may obtain a copy of the License at
+
+        http://www.apache.org/licenses/LICENSE-2.0
+
+    Unless required by applicable law or agreed to in writing, software
+    distributed under the License is distributed on an "AS IS" BASIS,
+    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+    See the License for the specific language governing permissions and
+    limitations under the License.
+*/
+
+use crate::griddb::Error::*;
+use std::cell::{Cell, RefCell};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::thread;
+use std::time::Duration;
+
+// Operations which can be retried by RetryPolicy
+#[derive(PartialEq, Eq, Copy, Debug, Clone)]
+pub enum OperationKind {
+    Put,
+    Get,
+    Fetch,
+}
+
+// Class RetryPolicy
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    // Total number of attempts, including the first one
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub multiplier: f64,
+    // Part of each backoff which is randomized, from 0.0 to 1.0
+    pub jitter: f64,
+    pub retry_on_timeout: bool,
+    pub operations: Vec<OperationKind>,
+}
+
+impl RetryPolicy {
+    // Retry every operation kind up to max_attempts times
+    pub fn new(max_attempts: u32, initial_backoff: Duration) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts,
+            initial_backoff,
+            max_backoff: Duration::from_secs(10),
+            multiplier: 2.0,
+            jitter: 0.5,
+            retry_on_timeout: true,
+            operations: vec![OperationKind::Put, OperationKind::Get, OperationKind::Fetch],
+        }
+    }
+
+    // Policy used by a store until set_retry_policy() is called
+    pub fn disabled() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 1,
+            initial_backoff: Duration::ZERO,
+            max_backoff: Duration::ZERO,
+            multiplier: 1.0,
+            jitter: 0.0,
+            retry_on_timeout: false,
+            operations: Vec::new(),
+        }
+    }
+
+    pub fn is_enabled_for(&self, kind: OperationKind) -> bool {
+        self.max_attempts > 1 && self.operations.contains(&kind)
+    }
+
+    pub fn should_retry(&self, error: &GridDbError) -> bool {
+        if error.is_timeout() {
+            return self.retry_on_timeout;
+        }
+        error.is_recoverable()
+    }
+
+    // Delay before the given retry, retry starts from 1
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let exponent = retry.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let delay = self.initial_backoff.as_secs_f64() * self.multiplier.powi(exponent);
+        let delay = delay.min(self.max_backoff.as_secs_f64()).max(0.0);
+        // A NaN jitter would pass through clamp() and make from_secs_f64() panic
+        let jitter = if self.jitter.is_finite() {
+            self.jitter.clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        Duration::from_secs_f64(delay * (1.0 - jitter * random_fraction(retry)))
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy::disabled()
+    }
+}
+
+// Retry policy shared by a store and every container and query opened from it
+pub struct RetryHandler {
+    _policy: RefCell<RetryPolicy>,
+    _retry_count: Cell<u64>,
+}
+
+impl RetryHandler {
+    pub fn new(policy: RetryPolicy) -> RetryHandler {
+        RetryHandler {
+            _policy: RefCell::new(policy),
+            _retry_count: Cell::new(0),
+        }
+    }
+
+    pub fn policy(&self) -> RetryPolicy {
+        self._policy.borrow().clone()
+    }
+
+    pub fn set_policy(&self, policy: RetryPolicy) {
+        *self._policy.borrow_mut() = policy;
+    }
+
+    // Number of retries performed since the store was opened
+    pub fn retry_count(&self) -> u64 {
+        self._retry_count.get()
+    }
+
+    // Run operation, retrying it while the policy allows it
+    pub fn run<T, F>(&self, kind: OperationKind, mut operation: F) -> Result<T, GridDbError>
+    where
+        F: FnMut() -> Result<T, GridDbError>,
+    {
+        let policy = self.policy();
+        let mut attempt: u32 = 1;
+        loop {
+            match operation() {
+                Err(error)
+                    if policy.is_enabled_for(kind)
+                        && attempt < policy.max_attempts
+                        && policy.should_retry(&error) =>
+                {
+                    thread::sleep(policy.backoff(attempt));
+                    attempt += 1;
+                    self._retry_count.set(self._retry_count.get() + 1);
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+// Random number in [0, 1) without an extra dependency
+fn random_fraction(seed: u32) -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u32(seed);
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(jitter: f64) -> RetryPolicy {
+        RetryPolicy {
+            max_backoff: Duration::from_millis(500),
+            jitter,
+            ..RetryPolicy::new(5, Duration::from_millis(100))
+        }
+    }
+
+    #[test]
+    fn backoff_grows_by_multiplier() {
+        let policy = policy(0.0);
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+    }
+
+    #[test]
+    fn backoff_is_capped_by_max_backoff() {
+        let policy = policy(0.0);
+        assert_eq!(policy.backoff(4), Duration::from_millis(500));
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn backoff_with_jitter_stays_below_delay() {
+        let policy = policy(1.0);
+        for retry in 1..20 {
+            assert!(policy.backoff(retry) <= Duration::from_millis(500));
+        }
+    }
+
+    #[test]
+    fn backoff_ignores_non_finite_jitter() {
+        for jitter in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert_eq!(policy(jitter).backoff(1), Duration::from_millis(100));
+        }
+    }
+
+    #[test]
+    fn backoff_of_disabled_policy_is_zero() {
+        assert_eq!(RetryPolicy::disabled().backoff(1), Duration::ZERO);
+    }
+}
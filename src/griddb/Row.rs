@@ -0,0 +1,210 @@
+/*
+    Copyright (c) 2022 TOSHIBA Digital Solutions Corporation.
+
+    Licensed under the Apache License, Version 2.0 (the "License");
+    you may not use this file except in compliance with the License.
+    You may obtain a copy of the License at
+
+        http://www.apache.org/licenses/LICENSE-2.0
+
+    Unless required by applicable law or agreed to in writing, software
+    distributed under the License is distributed on an "AS IS" BASIS,
+    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+    See the License for the specific language governing permissions and
+    limitations under the License.
+*/
+
+use crate::griddb::Const::*;
+use crate::griddb::Error::*;
+use crate::griddb::Value::*;
+use std::ops::Index;
+use std::rc::Rc;
+
+// Class Row: values of one row with the column names shared by its row set
+#[derive(Debug, Clone)]
+pub struct Row {
+    _name_list: Rc<Vec<String>>,
+    _values: Vec<Value>,
+}
+
+impl Row {
+    pub fn new(name_list: Rc<Vec<String>>, values: Vec<Value>) -> Row {
+        Row {
+            _name_list: name_list,
+            _values: values,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self._values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self._values.is_empty()
+    }
+
+    pub fn column_names(&self) -> &[String] {
+        &self._name_list
+    }
+
+    pub fn values(&self) -> &[Value] {
+        &self._values
+    }
+
+    // Values in column order, as accepted by Container::put
+    pub fn into_values(self) -> Vec<Value> {
+        self._values
+    }
+
+    // Position of a column, names are compared ignoring ASCII case
+    pub fn position(&self, name: &str) -> Option<usize> {
+        self._name_list
+            .iter()
+            .position(|column| column.eq_ignore_ascii_case(name))
+    }
+
+    pub fn value(&self, name: &str) -> Option<&Value> {
+        self.position(name).map(|pos| &self._values[pos])
+    }
+
+    // Convert the value of a column, e.g. row.get::<f64>("voltage")
+    pub fn get<'a, T>(&'a self, name: &str) -> Result<T, GridDbError>
+    where
+        T: TryFrom<&'a Value, Error = GridDbError>,
+    {
+        match self.value(name) {
+            Some(value) => T::try_from(value).map_err(|error| {
+                GridDbError::new(
+                    ERROR_CONVERT_DATA,
+                    &format!("Column {}: {}", name, error.message()),
+                )
+            }),
+            None => Err(GridDbError::new(
+                ERROR_CONVERT_DATA,
+                &format!("Column {} does not exist in the row", name),
+            )),
+        }
+    }
+
+    // (column name, value) pairs in column order
+    pub fn iter(&self) -> <&Row as IntoIterator>::IntoIter {
+        self.into_iter()
+    }
+}
+
+impl Index<usize> for Row {
+    type Output = Value;
+
+    fn index(&self, pos: usize) -> &Value {
+        &self._values[pos]
+    }
+}
+
+impl Index<&str> for Row {
+    type Output = Value;
+
+    fn index(&self, name: &str) -> &Value {
+        match self.value(name) {
+            Some(value) => value,
+            None => panic!("Column {} does not exist in the row", name),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Row {
+    type Item = (&'a str, &'a Value);
+    type IntoIter = std::iter::Zip<
+        std::iter::Map<std::slice::Iter<'a, String>, fn(&String) -> &str>,
+        std::slice::Iter<'a, Value>,
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self._name_list
+            .iter()
+            .map(String::as_str as fn(&String) -> &str)
+            .zip(self._values.iter())
+    }
+}
+
+impl From<Row> for Vec<Value> {
+    fn from(row: Row) -> Self {
+        row.into_values()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row() -> Row {
+        let name_list = vec![
+            "timestamp".to_string(),
+            "Active".to_string(),
+            "voltage".to_string(),
+        ];
+        Row::new(
+            Rc::new(name_list),
+            vec![
+                Value::Timestamp(Timestamp { value: 1000 }),
+                Value::Bool(true),
+                Value::Null,
+            ],
+        )
+    }
+
+    #[test]
+    fn index_by_position_and_name() {
+        let row = row();
+        assert_eq!(row.len(), 3);
+        assert!(matches!(
+            row[0],
+            Value::Timestamp(Timestamp { value: 1000 })
+        ));
+        assert!(matches!(row[1], Value::Bool(true)));
+        assert!(matches!(row["active"], Value::Bool(true)));
+        assert!(matches!(row["ACTIVE"], Value::Bool(true)));
+        assert!(matches!(row["voltage"], Value::Null));
+        assert_eq!(row.position("Voltage"), Some(2));
+        assert!(row.value("current").is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Column current does not exist in the row")]
+    fn index_by_unknown_name_panics() {
+        let _ = &row()["current"];
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_range_panics() {
+        let _ = &row()[3];
+    }
+
+    #[test]
+    fn get_converts_value() {
+        let row = row();
+        assert!(row.get::<bool>("active").unwrap());
+        assert_eq!(row.get::<Timestamp>("TIMESTAMP").unwrap().value, 1000);
+        assert_eq!(row.get::<Option<f64>>("voltage").unwrap(), None);
+
+        let error = row.get::<i64>("active").unwrap_err();
+        assert_eq!(error.code, ERROR_CONVERT_DATA);
+        assert!(error.message().starts_with("Column active: "));
+        let error = row.get::<bool>("current").unwrap_err();
+        assert_eq!(error.code, ERROR_CONVERT_DATA);
+        assert_eq!(error.message(), "Column current does not exist in the row");
+    }
+
+    #[test]
+    fn iter_in_column_order() {
+        let row = row();
+        let names: Vec<&str> = row.iter().map(|(name, _)| name).collect();
+        assert_eq!(names, vec!["timestamp", "Active", "voltage"]);
+        assert!(matches!(
+            row.iter().nth(1),
+            Some(("Active", Value::Bool(true)))
+        ));
+        let values: Vec<Value> = row.into();
+        assert_eq!(values.len(), 3);
+    }
+}
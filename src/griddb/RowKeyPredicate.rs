@@ -0,0 +1,224 @@
+/*
+    Copyright (c) 2022 TOSHIBA Digital Solutions Corporation.
+
+    Licensed under the Apache License, Version 2.0 (the "License");
+    you may not use this file except in compliance with the License.
+    You may obtain a copy of the License at
+
+        http://www.apache.org/licenses/LICENSE-2.0
+
+    Unless required by applicable law or agreed to in writing, software
+    distributed under the License is distributed on an "AS IS" BASIS,
+    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+    See the License for the specific language governing permissions and
+    limitations under the License.
+*/
+
+extern crate griddb_sys as ffi;
+
+use crate::griddb::Const::*;
+use crate::griddb::ContainerInfo::*;
+use crate::griddb::Error::*;
+use crate::griddb::Type::*;
+use crate::griddb::Util::*;
+use crate::griddb::Value::*;
+use std::ffi::c_void;
+use std::ffi::CString;
+
+// Class RowKeyPredicate: selects rows by a key range (start and finish) or by
+// distinct keys, for Store::get_multiple_container_rows().
+// A simple predicate takes STRING, INTEGER, LONG or TIMESTAMP keys as Value,
+// a general predicate takes keys of any row key schema as a list of Value
+pub struct RowKeyPredicate {
+    _ptr: *mut ffi::GSRowKeyPredicate,
+    _store: *mut ffi::GSGridStore,
+    _key_type: Type,
+    // Row key schema, only for a general predicate
+    _key_info: Option<ContainerInfo>,
+}
+
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+impl RowKeyPredicate {
+    pub fn new(
+        predicate: *mut ffi::GSRowKeyPredicate,
+        store: *mut ffi::GSGridStore,
+        key_type: Type,
+        key_info: Option<ContainerInfo>,
+    ) -> RowKeyPredicate {
+        RowKeyPredicate {
+            _ptr: predicate,
+            _store: store,
+            _key_type: key_type,
+            _key_info: key_info,
+        }
+    }
+
+    pub(crate) fn as_ptr(&self) -> *mut ffi::GSRowKeyPredicate {
+        self._ptr
+    }
+
+    // Type of the first key column
+    pub fn key_type(&self) -> Type {
+        self._key_type
+    }
+
+    pub fn is_general(&self) -> bool {
+        self._key_info.is_some()
+    }
+
+    fn check(&self, ret: i32) -> Result<(), GridDbError> {
+        GridDbError::check(ret, self._ptr as *mut c_void)
+    }
+
+    fn key_type_error(&self, key: &Value) -> GridDbError {
+        GridDbError::new(
+            ERROR_CONVERT_DATA,
+            &format!(
+                "Key is {} but the predicate key type is {:?}",
+                key.type_name(),
+                self._key_type
+            ),
+        )
+    }
+
+    fn general_error(&self) -> GridDbError {
+        let message = if self.is_general() {
+            "Keys of a general predicate are given as a list of values"
+        } else {
+            "Keys of a simple predicate are given as a single value"
+        };
+        GridDbError::new(ERROR_CONVERT_DATA, message)
+    }
+
+    // Lower bound of the key range, inclusive
+    pub fn set_start(&self, key: &Value) -> Result<(), GridDbError> {
+        if self.is_general() {
+            return Err(self.general_error());
+        }
+        let ret;
+        match (self._key_type, key) {
+            (Type::String, Value::Str(value)) => {
+                let c_string = CString::new(value.as_str()).unwrap();
+                unsafe {
+                    ret = ffi::gsSetPredicateStartKeyByString(self._ptr, c_string.as_ptr());
+                }
+            }
+            (Type::Integer, Value::Integer(value)) => unsafe {
+                ret = ffi::gsSetPredicateStartKeyByInteger(self._ptr, value);
+            },
+            (Type::Long, Value::Long(value)) => unsafe {
+                ret = ffi::gsSetPredicateStartKeyByLong(self._ptr, value);
+            },
+            (Type::Timestamp, Value::Timestamp(value)) => unsafe {
+                ret = ffi::gsSetPredicateStartKeyByTimestamp(self._ptr, &value.value);
+            },
+            _ => return Err(self.key_type_error(key)),
+        }
+        self.check(ret)
+    }
+
+    // Upper bound of the key range, inclusive
+    pub fn set_finish(&self, key: &Value) -> Result<(), GridDbError> {
+        if self.is_general() {
+            return Err(self.general_error());
+        }
+        let ret;
+        match (self._key_type, key) {
+            (Type::String, Value::Str(value)) => {
+                let c_string = CString::new(value.as_str()).unwrap();
+                unsafe {
+                    ret = ffi::gsSetPredicateFinishKeyByString(self._ptr, c_string.as_ptr());
+                }
+            }
+            (Type::Integer, Value::Integer(value)) => unsafe {
+                ret = ffi::gsSetPredicateFinishKeyByInteger(self._ptr, value);
+            },
+            (Type::Long, Value::Long(value)) => unsafe {
+                ret = ffi::gsSetPredicateFinishKeyByLong(self._ptr, value);
+            },
+            (Type::Timestamp, Value::Timestamp(value)) => unsafe {
+                ret = ffi::gsSetPredicateFinishKeyByTimestamp(self._ptr, &value.value);
+            },
+            _ => return Err(self.key_type_error(key)),
+        }
+        self.check(ret)
+    }
+
+    // Add a key to match exactly. Cannot be combined with a key range
+    pub fn add_key(&self, key: &Value) -> Result<(), GridDbError> {
+        if self.is_general() {
+            return Err(self.general_error());
+        }
+        let ret;
+        match (self._key_type, key) {
+            (Type::String, Value::Str(value)) => {
+                let c_string = CString::new(value.as_str()).unwrap();
+                unsafe {
+                    ret = ffi::gsAddPredicateKeyByString(self._ptr, c_string.as_ptr());
+                }
+            }
+            (Type::Integer, Value::Integer(value)) => unsafe {
+                ret = ffi::gsAddPredicateKeyByInteger(self._ptr, *value);
+            },
+            (Type::Long, Value::Long(value)) => unsafe {
+                ret = ffi::gsAddPredicateKeyByLong(self._ptr, *value);
+            },
+            (Type::Timestamp, Value::Timestamp(value)) => unsafe {
+                ret = ffi::gsAddPredicateKeyByTimestamp(self._ptr, value.value);
+            },
+            _ => return Err(self.key_type_error(key)),
+        }
+        self.check(ret)
+    }
+
+    pub fn add_keys(&self, keys: &[Value]) -> Result<(), GridDbError> {
+        for key in keys {
+            self.add_key(key)?;
+        }
+        Ok(())
+    }
+
+    // Run a setter taking a row key object, which is copied by the predicate
+    fn with_general_key<F>(&self, key: &[Value], set: F) -> Result<(), GridDbError>
+    where
+        F: FnOnce(*mut ffi::GSRowKey) -> i32,
+    {
+        let key_info = match &self._key_info {
+            Some(key_info) => key_info,
+            None => return Err(self.general_error()),
+        };
+        let mut gs_key = Util::create_row_key(self._store, key_info, key)?;
+        let ret = set(gs_key);
+        unsafe {
+            ffi::gsCloseRow(&mut gs_key);
+        }
+        self.check(ret)
+    }
+
+    pub fn set_start_general_key(&self, key: &[Value]) -> Result<(), GridDbError> {
+        self.with_general_key(key, |gs_key| unsafe {
+            ffi::gsSetPredicateStartGeneralKey(self._ptr, gs_key)
+        })
+    }
+
+    pub fn set_finish_general_key(&self, key: &[Value]) -> Result<(), GridDbError> {
+        self.with_general_key(key, |gs_key| unsafe {
+            ffi::gsSetPredicateFinishGeneralKey(self._ptr, gs_key)
+        })
+    }
+
+    pub fn add_general_key(&self, key: &[Value]) -> Result<(), GridDbError> {
+        self.with_general_key(key, |gs_key| unsafe {
+            ffi::gsAddPredicateGeneralKey(self._ptr, gs_key)
+        })
+    }
+}
+
+// Destructor
+impl Drop for RowKeyPredicate {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::gsCloseRowKeyPredicate(&mut self._ptr);
+        }
+    }
+}
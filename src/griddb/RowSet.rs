@@ -18,10 +18,15 @@ extern crate griddb_sys as ffi;
 
 use crate::griddb::AggregationResult::*;
 use crate::griddb::Const::*;
+use crate::griddb::Error::*;
+use crate::griddb::QueryAnalysisEntry::*;
+use crate::griddb::Row::*;
 use crate::griddb::Type::*;
 use crate::griddb::Util::*;
 use crate::griddb::Value::*;
+use std::ffi::c_void;
 use std::ptr;
+use std::rc::Rc;
 
 // Class rowset
 pub struct RowSet {
@@ -29,11 +34,21 @@ pub struct RowSet {
     _row: *mut ffi::GSRow,
     pub rowset_type: RowSetType,
     pub size: i32,
+    _name_list: Rc<Vec<String>>,
     _type_list: Vec<Type>,
+    _nullable_list: Vec<bool>,
+    // Set once iteration returned an error, so that it does not repeat
+    _failed: bool,
 }
 #[allow(clippy::not_unsafe_ptr_arg_deref)]
 impl RowSet {
-    pub fn new(row_set: *mut ffi::GSRowSet, row: *mut ffi::GSRow, type_list: Vec<Type>) -> RowSet {
+    pub fn new(
+        row_set: *mut ffi::GSRowSet,
+        row: *mut ffi::GSRow,
+        name_list: Vec<String>,
+        type_list: Vec<Type>,
+        nullable_list: Vec<bool>,
+    ) -> RowSet {
         let size: i32;
         let gs_type;
         let rowset_type: RowSetType;
@@ -60,9 +75,16 @@ impl RowSet {
             _row: row,
             rowset_type,
             size,
+            _name_list: Rc::new(name_list),
             _type_list: type_list,
+            _nullable_list: nullable_list,
+            _failed: false,
         }
     }
+    // Column names of the rows returned by next() and next_values()
+    pub fn column_names(&self) -> &[String] {
+        &self._name_list
+    }
     pub fn has_next(&self) -> bool {
         let result;
         unsafe {
@@ -70,7 +92,20 @@ impl RowSet {
         }
         result == ffi::GS_TRUE as i8
     }
-    pub fn next(&self) -> Result<Vec<Value>, i32> {
+    fn check(&self, ret: i32) -> Result<(), GridDbError> {
+        GridDbError::check(ret, self._ptr as *mut c_void)
+    }
+    fn row_set_type_error(&self) -> GridDbError {
+        GridDbError::new(
+            ERROR_CONVERT_DATA,
+            &format!(
+                "Operation is not supported for {:?} row set",
+                self.rowset_type
+            ),
+        )
+    }
+    // Values of the next row. next() is Iterator::next(), which returns a Row
+    pub fn next_values(&self) -> Result<Vec<Value>, GridDbError> {
         let ret;
         let type_rs = self.get_row_set_type();
         match type_rs as u32 {
@@ -78,18 +113,41 @@ impl RowSet {
                 unsafe {
                     ret = ffi::gsGetNextRow(self._ptr, self._row as *mut std::ffi::c_void);
                 }
-                if ret == ffi::GS_RESULT_OK as i32 {
-                    let mut vec = Vec::new();
-                    Util::get_row_data(self._row, &mut vec, &self._type_list);
-                    Ok(vec)
-                } else {
-                    Err(ret)
-                }
+                self.check(ret)?;
+                let mut vec = Vec::new();
+                Util::get_row_data(self._row, &mut vec, &self._type_list)?;
+                Ok(vec)
             }
-            _ => Err(ERROR_CONVERT_DATA),
+            _ => Err(self.row_set_type_error()),
         }
     }
-    pub fn next_aggregation(&self) -> Result<AggregationResult, i32> {
+    // Replace the row last returned by next() or next_values() with fields.
+    // The row set must be fetched by Query::fetch_for_update()
+    pub fn update_current(&self, fields: Vec<Value>) -> Result<(), GridDbError> {
+        if self.rowset_type != RowSetType::ContainerRows {
+            return Err(self.row_set_type_error());
+        }
+        Util::check_row(&fields, &self._type_list, &self._nullable_list)?;
+        Util::bind_row(&fields, self._row)?;
+        let ret;
+        unsafe {
+            ret = ffi::gsUpdateCurrentRow(self._ptr, self._row as *const c_void);
+        }
+        self.check(ret)
+    }
+    // Delete the row last returned by next() or next_values().
+    // The row set must be fetched by Query::fetch_for_update()
+    pub fn delete_current(&self) -> Result<(), GridDbError> {
+        if self.rowset_type != RowSetType::ContainerRows {
+            return Err(self.row_set_type_error());
+        }
+        let ret;
+        unsafe {
+            ret = ffi::gsDeleteCurrentRow(self._ptr);
+        }
+        self.check(ret)
+    }
+    pub fn next_aggregation(&self) -> Result<AggregationResult, GridDbError> {
         let ret;
         let type_rs = self.get_row_set_type();
         let mut aff_result: *mut ffi::GSAggregationResult = ptr::null_mut();
@@ -99,13 +157,34 @@ impl RowSet {
                 unsafe {
                     ret = ffi::gsGetNextAggregation(self._ptr, &mut aff_result);
                 }
-                if ret == ffi::GS_RESULT_OK as i32 {
-                    Ok(AggregationResult::new(aff_result))
-                } else {
-                    Err(ret)
+                self.check(ret)?;
+                Ok(AggregationResult::new(aff_result))
+            }
+            _ => Err(self.row_set_type_error()),
+        }
+    }
+    // Next plan entry of a row set fetched by EXPLAIN or EXPLAIN ANALYZE
+    pub fn next_query_analysis(&self) -> Result<QueryAnalysisEntry, GridDbError> {
+        let ret;
+        let type_rs = self.get_row_set_type();
+        let mut entry = ffi::GSQueryAnalysisEntry {
+            id: 0,
+            depth: 0,
+            type_: ptr::null(),
+            valueType: ptr::null(),
+            value: ptr::null(),
+            statement: ptr::null(),
+        };
+        match type_rs as u32 {
+            ffi::GSRowSetTypeTag_GS_ROW_SET_QUERY_ANALYSIS => {
+                unsafe {
+                    ret = ffi::gsGetNextQueryAnalysis(self._ptr, &mut entry);
                 }
+                self.check(ret)?;
+                // Strings of entry are owned by the row set, copy them now
+                Ok(QueryAnalysisEntry::from_gs(&entry))
             }
-            _ => Err(ERROR_CONVERT_DATA),
+            _ => Err(self.row_set_type_error()),
         }
     }
     fn get_row_set_type(&self) -> i32 {
@@ -117,6 +196,25 @@ impl RowSet {
     }
 }
 
+// Iterate the rows of a ContainerRows row set with their column names.
+// `for row in &mut row_set` works through the blanket impl for &mut Iterator
+impl Iterator for RowSet {
+    type Item = Result<Row, GridDbError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self._failed || !self.has_next() {
+            return None;
+        }
+        match self.next_values() {
+            Ok(values) => Some(Ok(Row::new(self._name_list.clone(), values))),
+            Err(error) => {
+                self._failed = true;
+                Some(Err(error))
+            }
+        }
+    }
+}
+
 // Destructor
 impl Drop for RowSet {
     fn drop(&mut self) {
@@ -16,32 +16,79 @@
 
 extern crate griddb_sys as ffi;
 
+use crate::griddb::Collection::*;
+use crate::griddb::Const::*;
 use crate::griddb::Container::*;
 use crate::griddb::ContainerInfo::*;
+use crate::griddb::Error::*;
+use crate::griddb::GridRow::*;
+use crate::griddb::IndexInfo::*;
+use crate::griddb::PartitionController::*;
+use crate::griddb::Query::*;
+use crate::griddb::RetryPolicy::*;
+use crate::griddb::Row::*;
+use crate::griddb::RowKeyPredicate::*;
+use crate::griddb::TimeSeries::*;
+use crate::griddb::TimeSeriesProperties::*;
+use crate::griddb::TriggerInfo::*;
 use crate::griddb::Type::*;
+use crate::griddb::TypedContainer::*;
+use crate::griddb::Util::*;
+use crate::griddb::Value::*;
 use crate::num_to_enum;
 
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ffi::c_void;
+use std::ffi::CStr;
 use std::ffi::CString;
 use std::ptr;
+use std::rc::Rc;
 
 // Constructor Class Store
 pub struct Store {
     _ptr: *mut ffi::GSGridStore,
+    _retry: Rc<RetryHandler>,
+    // Container info by lower case name, used to build rows without opening containers
+    _schema_cache: RefCell<HashMap<String, Rc<ContainerInfo>>>,
 }
 impl Store {
     pub fn new(store: *mut ffi::GSGridStore) -> Store {
-        Store { _ptr: store }
+        Store {
+            _ptr: store,
+            _retry: Rc::new(RetryHandler::new(RetryPolicy::disabled())),
+            _schema_cache: RefCell::new(HashMap::new()),
+        }
+    }
+
+    // Also applies to containers and queries already opened from this store
+    pub fn set_retry_policy(&self, policy: RetryPolicy) {
+        self._retry.set_policy(policy);
+    }
+
+    pub fn retry_policy(&self) -> RetryPolicy {
+        self._retry.policy()
+    }
+
+    // Total number of retries performed by this store, its containers and queries
+    pub fn retry_count(&self) -> u64 {
+        self._retry.retry_count()
+    }
+
+    fn check(&self, ret: i32) -> Result<(), GridDbError> {
+        GridDbError::check(ret, self._ptr as *mut c_void)
     }
 
     pub fn put_container(
         &self,
         container_info: &ContainerInfo,
         modifiable: bool,
-    ) -> Result<Container, i32> {
+    ) -> Result<Container, GridDbError> {
         let mut _container: *mut GSContainer = ptr::null_mut();
         let mut _gs_container_info = container_info.unwrap();
         let ret;
         let name_tmp: CString = CString::new(container_info.name.clone()).unwrap();
+        self.forget_schema(&container_info.name);
         unsafe {
             ret = ffi::gsPutContainerGeneralV4_3(
                 self._ptr,
@@ -51,49 +98,22 @@ impl Store {
                 &mut _container,
             );
         }
-        if ret == ffi::GS_RESULT_OK as i32 {
-            let mut type_list: Vec<Type> = Vec::new();
-            let vec_column_info;
-            unsafe {
-                vec_column_info = std::slice::from_raw_parts(
-                    _gs_container_info.columnInfoList,
-                    _gs_container_info.columnCount as usize,
-                )
-                .to_vec();
-            }
-            for column_info in vec_column_info {
-                type_list.push(num_to_enum!(
-                    column_info.type_ => Type<i32>{String,Bool, Byte, Short, Integer, Long, Float, Double,Timestamp, Geometry, Blob};
-                    panic!("Cannot convert number to `enum ContainerType`")
-                ));
-            }
-            let container_data: Result<Container, i32> = Container::new(
-                _container,
-                num_to_enum!(
-                    _gs_container_info.type_ => ContainerType<i32>{Collection, TimeSeries};
-                    panic!("Cannot convert number to `enum ContainerType`")
-                ),
-                type_list,
-            );
-            match container_data {
-                Ok(result) => Ok(result),
-                Err(error) => Err(error),
-            }
-        } else {
-            Err(ret)
-        }
+        self.check(ret)?;
+        Container::new(_container, self._ptr, container_info, self._retry.clone())
     }
 
     // Get container infor
-    pub fn get_container_info(&self, name: &str) -> Result<ContainerInfo, i32> {
+    pub fn get_container_info(&self, name: &str) -> Result<ContainerInfo, GridDbError> {
         let value_tmp = CString::new(name).expect("Error convert String to CString");
         let _name = value_tmp.as_ptr();
         let _gs_info: *mut ffi::GSContainerInfo;
         let _bool: *mut i8;
         let ret;
+        let exists;
         let mut row_key = false;
+        let mut row_key_column_list: Vec<i32> = vec![];
         let vec_column_info;
-        let mut tmp_vec_column_info: Vec<(&str, Type)> = vec![];
+        let mut tmp_vec_column_info: Vec<(&str, Type, TypeOption)> = vec![];
         let mut tmp_vec: Vec<(String, i32, i32)> = vec![];
         unsafe {
             // Malloc mem for output
@@ -102,19 +122,37 @@ impl Store {
             _bool = ffi::malloc(std::mem::size_of::<i8>() as u64) as *mut i8;
             // Call function C API
             ret = ffi::gsGetContainerInfoV4_3(self._ptr, _name, _gs_info, _bool);
+            exists = ret == ffi::GS_RESULT_OK as i32 && *_bool == ffi::GS_TRUE as i8;
             ffi::free(_bool as *mut _);
         }
         if ret != ffi::GS_RESULT_OK as i32 {
             unsafe {
                 ffi::free(_gs_info as *mut _);
             }
-            return Err(ret);
+            return Err(GridDbError::from_resource(ret, self._ptr as *mut c_void));
+        }
+        // _gs_info is left uninitialized when the container does not exist
+        if !exists {
+            unsafe {
+                ffi::free(_gs_info as *mut _);
+            }
+            return Err(Store::not_found_error(name));
         }
         unsafe {
             // get row key
             if (*_gs_info).rowKeyAssigned == ffi::GS_TRUE.try_into().unwrap() {
                 row_key = true;
             }
+            // get row key columns, a single row key may be reported without them
+            if row_key && (*_gs_info).rowKeyColumnCount > 0 {
+                row_key_column_list = std::slice::from_raw_parts(
+                    (*_gs_info).rowKeyColumnList,
+                    (*_gs_info).rowKeyColumnCount as usize,
+                )
+                .to_vec();
+            } else if row_key {
+                row_key_column_list = vec![0];
+            }
             // get vec column info
             vec_column_info = std::slice::from_raw_parts(
                 (*_gs_info).columnInfoList,
@@ -123,7 +161,7 @@ impl Store {
             .to_vec();
             for x in vec_column_info {
                 tmp_vec.push((
-                    std::ffi::CStr::from_ptr(x.name as *const i8)
+                    std::ffi::CStr::from_ptr(x.name)
                         .to_string_lossy()
                         .into_owned(),
                     x.type_,
@@ -132,15 +170,49 @@ impl Store {
             }
         }
         for i in &tmp_vec {
+            let option_type = if i.2 & ffi::GSTypeOptionTag_GS_TYPE_OPTION_NULLABLE as i32 != 0 {
+                TypeOption::Nullable
+            } else {
+                TypeOption::NotNull
+            };
             tmp_vec_column_info.push((
                 i.0.as_str(),
                 num_to_enum!(
                     i.1 => Type<i32> { String, Bool, Byte, Short,
-                        Integer, Long, Float, Double, Timestamp, Blob };
+                        Integer, Long, Float, Double, Timestamp, Geometry, Blob,
+                        StringArray, BoolArray, ByteArray, ShortArray, IntegerArray,
+                        LongArray, FloatArray, DoubleArray, TimestampArray };
                     panic!("Cannot convert number to `enum Type`")
                 ),
+                option_type,
             ));
         }
+        let mut index_info_list = vec![];
+        let mut time_series_properties = None;
+        let mut trigger_info_list = vec![];
+        unsafe {
+            if (*_gs_info).triggerInfoCount > 0 && !(*_gs_info).triggerInfoList.is_null() {
+                for trigger_info in std::slice::from_raw_parts(
+                    (*_gs_info).triggerInfoList,
+                    (*_gs_info).triggerInfoCount as usize,
+                ) {
+                    trigger_info_list.push(TriggerInfo::from_gs(trigger_info));
+                }
+            }
+            if !(*_gs_info).timeSeriesProperties.is_null() {
+                time_series_properties = Some(TimeSeriesProperties::from_gs(
+                    &*(*_gs_info).timeSeriesProperties,
+                ));
+            }
+            if (*_gs_info).indexInfoCount > 0 && !(*_gs_info).indexInfoList.is_null() {
+                for index_info in std::slice::from_raw_parts(
+                    (*_gs_info).indexInfoList,
+                    (*_gs_info).indexInfoCount as usize,
+                ) {
+                    index_info_list.push(IndexInfo::from_gs(index_info));
+                }
+            }
+        }
         let container_type;
         unsafe {
             container_type = num_to_enum!(
@@ -148,89 +220,397 @@ impl Store {
                 panic!("Cannot convert number to `enum ContainerType`")
             );
             ffi::free(_gs_info as *mut _);
-            Ok(ContainerInfo::ContainerInfo(
+            let mut container_info = ContainerInfo::with_row_key_columns(
                 name,
                 tmp_vec_column_info,
                 container_type,
-                row_key,
-            ))
+                row_key_column_list,
+            );
+            container_info.set_index_info_list(index_info_list);
+            container_info.set_time_series_properties(time_series_properties);
+            container_info.set_trigger_info_list(trigger_info_list);
+            Ok(container_info)
         }
     }
 
     // Get container
-    pub fn get_container(&self, name: &str) -> Result<Container, i32> {
+    pub fn get_container(&self, name: &str) -> Result<Container, GridDbError> {
         let mut container: *mut GSContainer = ptr::null_mut();
         let value_tmp = CString::new(name).expect("Error convert String to CString");
-        let name = value_tmp.as_ptr();
-        let mut ret;
-        let _gs_info: *mut ffi::GSContainerInfo;
-        let _bool: *mut i8;
+        let ret;
 
         unsafe {
-            ret = ffi::gsGetContainerGeneral(self._ptr, name, &mut container);
-            if ret != ffi::GS_RESULT_OK as i32 {
-                return Err(ret);
-            }
-            _gs_info = ffi::malloc(std::mem::size_of::<ffi::GSContainerInfo>() as u64)
-                as *mut ffi::GSContainerInfo;
-            _bool = ffi::malloc(std::mem::size_of::<i8>() as u64) as *mut i8;
-            ret = ffi::gsGetContainerInfoV4_3(self._ptr, name, _gs_info, _bool);
-            ffi::free(_bool as *mut _);
+            ret = ffi::gsGetContainerGeneral(self._ptr, value_tmp.as_ptr(), &mut container);
         }
-        if ret != ffi::GS_RESULT_OK as i32 {
+        self.check(ret)?;
+        // The container is NULL when it does not exist
+        if container.is_null() {
+            return Err(Store::not_found_error(name));
+        }
+        let result = self.get_container_info(name).and_then(|container_info| {
+            Container::new(container, self._ptr, &container_info, self._retry.clone())
+        });
+        if result.is_err() {
             unsafe {
-                ffi::free(_gs_info as *mut _);
+                ffi::gsCloseContainer(&mut container, ffi::GS_FALSE as i8);
             }
-            return Err(ret);
         }
+        result
+    }
 
-        let mut type_list: Vec<Type> = Vec::new();
-        let vec_column_info;
-        unsafe {
-            vec_column_info = std::slice::from_raw_parts(
-                (*_gs_info).columnInfoList,
-                (*_gs_info).columnCount as usize,
+    fn not_found_error(name: &str) -> GridDbError {
+        GridDbError::new(
+            ERROR_CONTAINER_NOT_FOUND,
+            &format!("Container {} is not found", name),
+        )
+    }
+
+    // Get a Collection container, failing when it is a TimeSeries
+    pub fn get_collection(&self, name: &str) -> Result<Collection, GridDbError> {
+        Collection::new(self.get_container(name)?)
+    }
+
+    // Get a TimeSeries container, failing when it is a Collection
+    pub fn get_time_series(&self, name: &str) -> Result<TimeSeries, GridDbError> {
+        TimeSeries::new(self.get_container(name)?)
+    }
+
+    // Get container with rows mapped to T, failing when its schema does not match T
+    pub fn get_typed_container<T: GridRow>(
+        &self,
+        name: &str,
+    ) -> Result<TypedContainer<T>, GridDbError> {
+        TypedContainer::new(self.get_container(name)?)
+    }
+
+    // Create or update container with the schema of T
+    pub fn put_typed_container<T: GridRow>(
+        &self,
+        name: &str,
+        container_type: ContainerType,
+        modifiable: bool,
+    ) -> Result<TypedContainer<T>, GridDbError> {
+        let container_info = T::container_info(name, container_type);
+        TypedContainer::new(self.put_container(&container_info, modifiable)?)
+    }
+
+    // Container info of name, fetched from the server on first use only
+    fn cached_container_info(&self, name: &str) -> Result<Rc<ContainerInfo>, GridDbError> {
+        let key = name.to_ascii_lowercase();
+        if let Some(info) = self._schema_cache.borrow().get(&key) {
+            return Ok(info.clone());
+        }
+        let info = Rc::new(self.get_container_info(name)?);
+        self._schema_cache.borrow_mut().insert(key, info.clone());
+        Ok(info)
+    }
+
+    fn forget_schema(&self, name: &str) {
+        self._schema_cache
+            .borrow_mut()
+            .remove(&name.to_ascii_lowercase());
+    }
+
+    // Forget the schemas cached by put_multiple_container_rows(), needed when
+    // containers are altered by another client
+    pub fn clear_schema_cache(&self) {
+        self._schema_cache.borrow_mut().clear();
+    }
+
+    // Put rows into many containers with one gsPutMultipleContainerRows call.
+    // Rows hold values only, the schema of each container is cached by the store
+    pub fn put_multiple_container_rows<R>(
+        &self,
+        container_rows: HashMap<String, Vec<R>>,
+    ) -> Result<(), GridDbError>
+    where
+        R: Into<Vec<Value>>,
+    {
+        let mut name_list: Vec<CString> = Vec::with_capacity(container_rows.len());
+        let mut row_lists: Vec<Vec<*mut ffi::GSRow>> = Vec::with_capacity(container_rows.len());
+        let mut all_row_key = true;
+        let result = self
+            .create_container_rows(
+                container_rows,
+                &mut name_list,
+                &mut row_lists,
+                &mut all_row_key,
             )
-            .to_vec();
+            .and_then(|()| {
+                let entry_list: Vec<ffi::GSContainerRowEntry> = name_list
+                    .iter()
+                    .zip(&row_lists)
+                    .map(|(name, row_list)| ffi::GSContainerRowEntry {
+                        containerName: name.as_ptr(),
+                        rowList: row_list.as_ptr() as *const *mut c_void,
+                        rowCount: row_list.len() as ffi::size_t,
+                    })
+                    .collect();
+                let put_rows = || {
+                    let ret;
+                    unsafe {
+                        ret = ffi::gsPutMultipleContainerRows(
+                            self._ptr,
+                            entry_list.as_ptr(),
+                            entry_list.len() as ffi::size_t,
+                        );
+                    }
+                    self.check(ret)
+                };
+                // Repeating the put only overwrites the same rows when every container has a row key
+                if all_row_key {
+                    self._retry.run(OperationKind::Put, put_rows)
+                } else {
+                    put_rows()
+                }
+            });
+        for row_list in row_lists.iter_mut() {
+            for row in row_list.iter_mut() {
+                unsafe {
+                    ffi::gsCloseRow(row);
+                }
+            }
         }
-        for column_info in vec_column_info {
-            type_list.push(num_to_enum!(
-                    column_info.type_ => Type<i32>{String,Bool, Byte, Short, Integer, Long, Float, Double,Timestamp, Geometry, Blob};
-                    panic!("Cannot convert number to `enum ContainerType`")
-            ));
+        result
+    }
+
+    // Validate and bind the rows of every container, created rows are added to
+    // row_lists even on error so that the caller closes them
+    fn create_container_rows<R>(
+        &self,
+        container_rows: HashMap<String, Vec<R>>,
+        name_list: &mut Vec<CString>,
+        row_lists: &mut Vec<Vec<*mut ffi::GSRow>>,
+        all_row_key: &mut bool,
+    ) -> Result<(), GridDbError>
+    where
+        R: Into<Vec<Value>>,
+    {
+        for (name, rows) in container_rows {
+            let info = self.cached_container_info(&name)?;
+            let type_list: Vec<Type> = info
+                .column_info_list
+                .iter()
+                .map(|column| column.1)
+                .collect();
+            let nullable_list: Vec<bool> = info
+                .column_info_list
+                .iter()
+                .map(|column| column.2 == TypeOption::Nullable)
+                .collect();
+            let gs_info = info.unwrap();
+            *all_row_key &= info.row_key;
+            name_list.push(CString::new(name.as_str()).expect("Error convert String to CString"));
+            row_lists.push(Vec::with_capacity(rows.len()));
+            let row_list = row_lists.last_mut().unwrap();
+            for (index, fields) in rows.into_iter().enumerate() {
+                let fields: Vec<Value> = fields.into();
+                Util::check_row(&fields, &type_list, &nullable_list).map_err(|error| {
+                    error.with_context(&format!("Container {} row {} is invalid", name, index))
+                })?;
+                let mut gs_row: *mut ffi::GSRow = ptr::null_mut();
+                let ret;
+                unsafe {
+                    ret = ffi::gsCreateRowByStoreV4_3(self._ptr, &gs_info, &mut gs_row);
+                }
+                self.check(ret)?;
+                row_list.push(gs_row);
+                Util::bind_row(&fields, gs_row).map_err(|error| {
+                    error.with_context(&format!("Container {} row {} cannot be bound", name, index))
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    // Predicate on simple row keys of key_type: STRING, INTEGER, LONG or TIMESTAMP
+    pub fn create_row_key_predicate(&self, key_type: Type) -> Result<RowKeyPredicate, GridDbError> {
+        let mut predicate: *mut ffi::GSRowKeyPredicate = ptr::null_mut();
+        let ret;
+        unsafe {
+            ret = ffi::gsCreateRowKeyPredicate(self._ptr, key_type as i32, &mut predicate);
         }
-        if ret == ffi::GS_RESULT_OK as i32 {
-            let cont_type;
+        self.check(ret)?;
+        Ok(RowKeyPredicate::new(predicate, self._ptr, key_type, None))
+    }
+
+    // Predicate on row keys of any schema, key_info lists the row key columns
+    pub fn create_row_key_predicate_general(
+        &self,
+        key_info: ContainerInfo,
+    ) -> Result<RowKeyPredicate, GridDbError> {
+        let key_type = match key_info.column_info_list.first() {
+            Some(column) => column.1,
+            None => {
+                return Err(GridDbError::new(
+                    ERROR_CONVERT_DATA,
+                    "Row key schema has no column",
+                ))
+            }
+        };
+        let mut predicate: *mut ffi::GSRowKeyPredicate = ptr::null_mut();
+        let gs_info = key_info.unwrap();
+        let ret;
+        unsafe {
+            ret = ffi::gsCreateRowKeyPredicateGeneral(self._ptr, &gs_info, &mut predicate);
+        }
+        self.check(ret)?;
+        Ok(RowKeyPredicate::new(
+            predicate,
+            self._ptr,
+            key_type,
+            Some(key_info),
+        ))
+    }
+
+    // Get the rows matching a predicate in each container with one
+    // gsGetMultipleContainerRows call, grouped by container name
+    pub fn get_multiple_container_rows(
+        &self,
+        predicates: &HashMap<String, RowKeyPredicate>,
+    ) -> Result<HashMap<String, Vec<Row>>, GridDbError> {
+        let name_list: Vec<CString> = predicates
+            .keys()
+            .map(|name| CString::new(name.as_str()).expect("Error convert String to CString"))
+            .collect();
+        let entry_list: Vec<ffi::GSRowKeyPredicateEntry> = name_list
+            .iter()
+            .zip(predicates.values())
+            .map(|(name, predicate)| ffi::GSRowKeyPredicateEntry {
+                containerName: name.as_ptr(),
+                predicate: predicate.as_ptr(),
+            })
+            .collect();
+        let entry_ptr_list: Vec<*const ffi::GSRowKeyPredicateEntry> =
+            entry_list.iter().map(|entry| entry as *const _).collect();
+        // Schemas are resolved first, no other store call may happen while
+        // the rows returned by gsGetMultipleContainerRows are read
+        let mut schemas: HashMap<String, (Rc<Vec<String>>, Vec<Type>)> = HashMap::new();
+        for name in predicates.keys() {
+            let info = self.cached_container_info(name)?;
+            let name_list = info
+                .column_info_list
+                .iter()
+                .map(|column| column.0.clone())
+                .collect();
+            let type_list = info
+                .column_info_list
+                .iter()
+                .map(|column| column.1)
+                .collect();
+            schemas.insert(name.to_ascii_lowercase(), (Rc::new(name_list), type_list));
+        }
+        self._retry.run(OperationKind::Get, || {
+            let mut row_entry_list: *const ffi::GSContainerRowEntry = ptr::null();
+            let mut row_entry_count: ffi::size_t = 0;
+            let ret;
             unsafe {
-                cont_type = num_to_enum!(
-                    (*_gs_info).type_ => ContainerType<i32>{Collection, TimeSeries};
-                    panic!("Cannot convert number to `enum ContainerType`")
+                ret = ffi::gsGetMultipleContainerRows(
+                    self._ptr,
+                    entry_ptr_list.as_ptr(),
+                    entry_ptr_list.len() as ffi::size_t,
+                    &mut row_entry_list,
+                    &mut row_entry_count,
                 );
-                ffi::free(_gs_info as *mut _);
             }
-            let container_data: Result<Container, i32> =
-                Container::new(container, cont_type, type_list);
-            match container_data {
-                Ok(result) => Ok(result),
-                Err(error) => Err(error),
+            self.check(ret)?;
+            if row_entry_count == 0 {
+                return Ok(HashMap::new());
             }
-        } else {
+            // Rows returned are owned by the store until the next call
+            let row_entries;
             unsafe {
-                ffi::free(_gs_info as *mut _);
+                row_entries = std::slice::from_raw_parts(row_entry_list, row_entry_count as usize);
+            }
+            let mut entries = Vec::with_capacity(row_entries.len());
+            for entry in row_entries {
+                let name;
+                let gs_rows: &[*mut c_void];
+                unsafe {
+                    name = CStr::from_ptr(entry.containerName)
+                        .to_string_lossy()
+                        .into_owned();
+                    gs_rows = if entry.rowCount == 0 {
+                        &[]
+                    } else {
+                        std::slice::from_raw_parts(entry.rowList, entry.rowCount as usize)
+                    };
+                }
+                let rows = match schemas.get(&name.to_ascii_lowercase()) {
+                    Some((name_list, type_list)) => gs_rows
+                        .iter()
+                        .map(|gs_row| {
+                            let mut values = Vec::new();
+                            Util::get_row_data(*gs_row as *mut ffi::GSRow, &mut values, type_list)
+                                .map(|_| Row::new(name_list.clone(), values))
+                        })
+                        .collect::<Result<Vec<Row>, GridDbError>>(),
+                    None => Err(GridDbError::new(
+                        ERROR_CONVERT_DATA,
+                        &format!("Rows of container {} were not requested", name),
+                    )),
+                };
+                entries.push((name, rows));
+            }
+            entries
+                .into_iter()
+                .map(|(name, rows)| rows.map(|rows| (name, rows)))
+                .collect()
+        })
+    }
+
+    // Execute queries in one round trip with gsFetchAll. The result of each
+    // query is then returned by Query::get_row_set()
+    pub fn fetch_all(&self, queries: &mut [Query]) -> Result<(), GridDbError> {
+        if queries.is_empty() {
+            return Ok(());
+        }
+        let query_list: Vec<*mut ffi::GSQuery> =
+            queries.iter().map(|query| query.as_ptr()).collect();
+        self._retry.run(OperationKind::Fetch, || {
+            let ret;
+            unsafe {
+                ret = ffi::gsFetchAll(
+                    self._ptr,
+                    query_list.as_ptr(),
+                    query_list.len() as ffi::size_t,
+                );
             }
-            Err(ret)
+            self.check(ret)
+        })
+    }
+
+    // Get the controller of the partitions of the cluster
+    pub fn get_partition_controller(&self) -> Result<PartitionController, GridDbError> {
+        let mut controller: *mut ffi::GSPartitionController = ptr::null_mut();
+        let ret;
+        unsafe {
+            ret = ffi::gsGetPartitionController(self._ptr, &mut controller);
+        }
+        self.check(ret)?;
+        Ok(PartitionController::new(controller))
+    }
+
+    // Names of every container of the database, partition by partition
+    pub fn list_containers(&self) -> Result<Vec<String>, GridDbError> {
+        let controller = self.get_partition_controller()?;
+        let mut name_list = vec![];
+        for partition_index in 0..controller.get_partition_count()? {
+            name_list.extend(controller.get_container_names(partition_index, 0, None)?);
         }
+        Ok(name_list)
     }
 
     // Drop container
-    pub fn drop_container(&self, name: &str) -> i32 {
+    pub fn drop_container(&self, name: &str) -> Result<(), GridDbError> {
         let value_tmp = CString::new(name).expect("Error convert value to CString");
         let name_ptr = value_tmp.as_ptr();
+        self.forget_schema(name);
         let ret;
         unsafe {
             ret = ffi::gsDropContainer(self._ptr, name_ptr);
         }
-        ret
+        self.check(ret)
     }
 }
 
@@ -16,9 +16,11 @@
 
 extern crate griddb_sys as ffi;
 
+use crate::griddb::Error::*;
 use crate::griddb::Store::*;
 use crate::griddb::Util::*;
 
+use std::ffi::c_void;
 use std::ffi::CString;
 use std::ptr;
 use std::vec::Vec;
@@ -40,7 +42,7 @@ impl StoreFactory {
     }
 
     // get Store Factory
-    pub fn get_store(&self, properties: Vec<(&str, &str)>) -> Result<Store, i32> {
+    pub fn get_store(&self, properties: Vec<(&str, &str)>) -> Result<Store, GridDbError> {
         let mut temp_vec: Vec<CString> = Vec::new();
         let _properties = Util::tup_to_properties(properties, &mut temp_vec);
         let mut _store: *mut ffi::GSGridStore = ptr::null_mut();
@@ -55,11 +57,8 @@ impl StoreFactory {
             );
         }
         drop(temp_vec);
-        if ret == ffi::GS_RESULT_OK as i32 {
-            Ok(Store::new(_store))
-        } else {
-            Err(ret)
-        }
+        GridDbError::check(ret, self._ptr as *mut c_void)?;
+        Ok(Store::new(_store))
     }
 
     // Get version GridDB rust client
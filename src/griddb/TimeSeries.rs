@@ -0,0 +1,235 @@
+/*
+    Copyright (c) 2022 TOSHIBA Digital Solutions Corporation.
+
+    Licensed under the Apache License, Version 2.0 (the "License");
+    you may not use this file except in compliance with the License.
+    You may obtain a copy of the License at
+
+        http://www.apache.org/licenses/LICENSE-2.0
+
+    Unless required by applicable law or agreed to in writing, software
+    distributed under the License is distributed on an "AS IS" BASIS,
+    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+    See the License for the specific language governing permissions and
+    limitations under the License.
+*/
+
+extern crate griddb_sys as ffi;
+
+use crate::griddb::AggregationResult::*;
+use crate::griddb::Const::*;
+use crate::griddb::Container::*;
+use crate::griddb::Error::*;
+use crate::griddb::Query::*;
+use crate::griddb::RetryPolicy::*;
+use crate::griddb::Type::*;
+use crate::griddb::Util::*;
+use crate::griddb::Value::*;
+use std::ffi::c_void;
+use std::ffi::CString;
+use std::ptr;
+
+// Class TimeSeries: container of type TimeSeries with the operations
+// specific to it. The generic operations are reached through container()
+pub struct TimeSeries {
+    _container: Container,
+}
+
+impl TimeSeries {
+    // Fails when container is not a TimeSeries
+    pub fn new(container: Container) -> Result<TimeSeries, GridDbError> {
+        if container.container_type != ContainerType::TimeSeries {
+            return Err(GridDbError::new(
+                ERROR_INVALID_STATE,
+                "Container is not a TimeSeries",
+            ));
+        }
+        Ok(TimeSeries {
+            _container: container,
+        })
+    }
+
+    pub fn container(&self) -> &Container {
+        &self._container
+    }
+
+    // Put a row whose time is set by the server to the current time,
+    // the value given for the row key column is ignored
+    pub fn append(&self, fields: Vec<Value>) -> Result<(), GridDbError> {
+        let container = &self._container;
+        container.validate_row(&fields)?;
+        let row = container.row_ptr();
+        Util::bind_row(&fields, row)?;
+        let ret;
+        unsafe {
+            ret = ffi::gsAppendTimeSeriesRow(
+                container.as_ptr(),
+                row as *const c_void,
+                ptr::null_mut(),
+            );
+        }
+        container.check(ret)
+    }
+
+    // Row nearest to base in the direction of time_operator, empty when no row exists
+    pub fn get_row_by_base_time(
+        &self,
+        base: Timestamp,
+        time_operator: TimeOperator,
+    ) -> Result<Vec<Value>, GridDbError> {
+        let container = &self._container;
+        container.retry().run(OperationKind::Get, || {
+            let mut row_exist: ffi::GSBool = ffi::GS_FALSE as i8;
+            let ret;
+            unsafe {
+                ret = ffi::gsGetRowByBaseTime(
+                    container.as_ptr(),
+                    base.value,
+                    time_operator as i32,
+                    container.row_ptr() as *mut c_void,
+                    &mut row_exist,
+                );
+            }
+            container.read_row(ret, row_exist)
+        })
+    }
+
+    // Row at base with column linearly interpolated from the rows around it.
+    // column must be numeric. Empty when no row can be interpolated
+    pub fn interpolate(&self, base: Timestamp, column: &str) -> Result<Vec<Value>, GridDbError> {
+        let container = &self._container;
+        let column_name = CString::new(column).expect("Error convert String to CString");
+        container.retry().run(OperationKind::Get, || {
+            let mut row_exist: ffi::GSBool = ffi::GS_FALSE as i8;
+            let ret;
+            unsafe {
+                ret = ffi::gsInterpolateTimeSeriesRow(
+                    container.as_ptr(),
+                    base.value,
+                    column_name.as_ptr(),
+                    container.row_ptr() as *mut c_void,
+                    &mut row_exist,
+                );
+            }
+            container.read_row(ret, row_exist)
+        })
+    }
+
+    // Aggregate column over the rows from start to end, both inclusive.
+    // None when the aggregation has no result, e.g. MINIMUM of no rows
+    pub fn aggregate(
+        &self,
+        start: Timestamp,
+        end: Timestamp,
+        column: &str,
+        aggregation: Aggregation,
+    ) -> Result<Option<AggregationResult>, GridDbError> {
+        let container = &self._container;
+        let column_name = CString::new(column).expect("Error convert String to CString");
+        container.retry().run(OperationKind::Get, || {
+            let mut result: *mut ffi::GSAggregationResult = ptr::null_mut();
+            let ret;
+            unsafe {
+                ret = ffi::gsAggregateTimeSeries(
+                    container.as_ptr(),
+                    start.value,
+                    end.value,
+                    column_name.as_ptr(),
+                    aggregation as i32,
+                    &mut result,
+                );
+            }
+            container.check(ret)?;
+            if result.is_null() {
+                return Ok(None);
+            }
+            Ok(Some(AggregationResult::new(result)))
+        })
+    }
+
+    // Rows from start to end, both inclusive, in ascending time order
+    pub fn query_by_range(&self, start: Timestamp, end: Timestamp) -> Result<Query, GridDbError> {
+        let container = &self._container;
+        let mut query: *mut ffi::GSQuery = ptr::null_mut();
+        let ret;
+        unsafe {
+            ret = ffi::gsQueryByTimeSeriesRange(
+                container.as_ptr(),
+                start.value,
+                end.value,
+                &mut query,
+            );
+        }
+        container.check(ret)?;
+        Ok(container.wrap_query(query))
+    }
+
+    // Rows from start to end, both inclusive, in the given order.
+    // A bound of None leaves the range open on that side
+    pub fn query_by_ordered_range(
+        &self,
+        start: Option<Timestamp>,
+        end: Option<Timestamp>,
+        order: QueryOrder,
+    ) -> Result<Query, GridDbError> {
+        let container = &self._container;
+        let start = start.map(|start| start.value);
+        let end = end.map(|end| end.value);
+        let mut query: *mut ffi::GSQuery = ptr::null_mut();
+        let ret;
+        unsafe {
+            ret = ffi::gsQueryByTimeSeriesOrderedRange(
+                container.as_ptr(),
+                start
+                    .as_ref()
+                    .map_or(ptr::null(), |start| start as *const i64),
+                end.as_ref().map_or(ptr::null(), |end| end as *const i64),
+                order as i32,
+                &mut query,
+            );
+        }
+        container.check(ret)?;
+        Ok(container.wrap_query(query))
+    }
+
+    // One row every interval unit from start to end. When no row exists at a
+    // sampling time, the values of columns are interpolated according to mode
+    pub fn query_by_sampling(
+        &self,
+        start: Timestamp,
+        end: Timestamp,
+        columns: &[&str],
+        mode: InterpolationMode,
+        interval: i32,
+        unit: TimeUnit,
+    ) -> Result<Query, GridDbError> {
+        let container = &self._container;
+        let column_names: Vec<CString> = columns
+            .iter()
+            .map(|column| CString::new(*column).expect("Error convert String to CString"))
+            .collect();
+        let column_ptrs: Vec<*const ffi::GSChar> =
+            column_names.iter().map(|column| column.as_ptr()).collect();
+        let mut query: *mut ffi::GSQuery = ptr::null_mut();
+        let ret;
+        unsafe {
+            ret = ffi::gsQueryByTimeSeriesSampling(
+                container.as_ptr(),
+                start.value,
+                end.value,
+                if column_ptrs.is_empty() {
+                    ptr::null()
+                } else {
+                    column_ptrs.as_ptr()
+                },
+                column_ptrs.len() as ffi::size_t,
+                mode as i32,
+                interval,
+                unit as i32,
+                &mut query,
+            );
+        }
+        container.check(ret)?;
+        Ok(container.wrap_query(query))
+    }
+}
@@ -0,0 +1,195 @@
+/*
+    Copyright (c) 2022 TOSHIBA Digital Solutions Corporation.
+
+    Licensed under the Apache License, Version 2.0 (the "License");
+    you may not use this file except in compliance with the License.
+    You may obtain a copy of the License at
+
+        http://www.apache.org/licenses/LICENSE-2.0
+
+    Unless required by applicable law or agreed to in writing, software
+    distributed under the License is distributed on an "AS IS" BASIS,
+    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+    See the License for the specific language governing permissions and
+    limitations under the License.
+*/
+
+extern crate griddb_sys as ffi;
+
+use crate::griddb::Type::*;
+use crate::num_to_enum;
+use std::ffi::CStr;
+use std::ffi::CString;
+use std::ptr;
+
+// Class ColumnCompression: thresholds of one column for SS or HI compression.
+// With relative, rate and span are used, otherwise width
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnCompression {
+    pub column_name: String,
+    pub relative: bool,
+    pub rate: f64,
+    pub span: f64,
+    pub width: f64,
+}
+
+impl ColumnCompression {
+    // Error limit given as an absolute value
+    pub fn absolute(column_name: &str, width: f64) -> ColumnCompression {
+        ColumnCompression {
+            column_name: column_name.to_string(),
+            relative: false,
+            rate: 0.0,
+            span: 0.0,
+            width,
+        }
+    }
+
+    // Error limit given as rate of the value span
+    pub fn relative(column_name: &str, rate: f64, span: f64) -> ColumnCompression {
+        ColumnCompression {
+            column_name: column_name.to_string(),
+            relative: true,
+            rate,
+            span,
+            width: 0.0,
+        }
+    }
+}
+
+// Class TimeSeriesProperties: row expiration and compression of a TimeSeries.
+// A negative time, window size or division count leaves the server default
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeSeriesProperties {
+    pub row_expiration_time: i32,
+    pub row_expiration_time_unit: TimeUnit,
+    pub expiration_division_count: i32,
+    pub compression_method: CompressionMethod,
+    pub compression_window_size: i32,
+    pub compression_window_size_unit: TimeUnit,
+    pub compression_list: Vec<ColumnCompression>,
+}
+
+impl Default for TimeSeriesProperties {
+    // Same as GS_TIME_SERIES_PROPERTIES_INITIALIZER
+    fn default() -> Self {
+        TimeSeriesProperties {
+            row_expiration_time: -1,
+            row_expiration_time_unit: TimeUnit::Day,
+            expiration_division_count: -1,
+            compression_method: CompressionMethod::No,
+            compression_window_size: -1,
+            compression_window_size_unit: TimeUnit::Day,
+            compression_list: vec![],
+        }
+    }
+}
+
+impl TimeSeriesProperties {
+    // Rows expire after time unit
+    pub fn with_expiration(time: i32, unit: TimeUnit) -> TimeSeriesProperties {
+        TimeSeriesProperties {
+            row_expiration_time: time,
+            row_expiration_time_unit: unit,
+            ..TimeSeriesProperties::default()
+        }
+    }
+
+    // Read properties returned by the C API
+    pub fn from_gs(properties: &ffi::GSTimeSeriesProperties) -> TimeSeriesProperties {
+        let mut compression_list = vec![];
+        unsafe {
+            if properties.compressionListSize > 0 && !properties.compressionList.is_null() {
+                for compression in std::slice::from_raw_parts(
+                    properties.compressionList,
+                    properties.compressionListSize as usize,
+                ) {
+                    compression_list.push(ColumnCompression {
+                        column_name: CStr::from_ptr(compression.columnName)
+                            .to_string_lossy()
+                            .into_owned(),
+                        relative: compression.relative == ffi::GS_TRUE as i8,
+                        rate: compression.rate,
+                        span: compression.span,
+                        width: compression.width,
+                    });
+                }
+            }
+        }
+        TimeSeriesProperties {
+            row_expiration_time: properties.rowExpirationTime,
+            row_expiration_time_unit: to_time_unit(properties.rowExpirationTimeUnit),
+            expiration_division_count: properties.expirationDivisionCount,
+            compression_method: num_to_enum!(
+                properties.compressionMethod => CompressionMethod<i32> { No, Ss, Hi };
+                CompressionMethod::No
+            ),
+            compression_window_size: properties.compressionWindowSize,
+            compression_window_size_unit: to_time_unit(properties.compressionWindowSizeUnit),
+            compression_list,
+        }
+    }
+}
+
+fn to_time_unit(unit: ffi::GSTimeUnit) -> TimeUnit {
+    num_to_enum!(
+        unit => TimeUnit<i32> { Year, Month, Day, Hour, Minute, Second, MilliSecond };
+        TimeUnit::Day
+    )
+}
+
+// GSTimeSeriesProperties with the column names it points to
+pub struct GsTimeSeriesProperties {
+    _column_names: Vec<CString>,
+    _compression_list: Vec<ffi::GSColumnCompression>,
+    _properties: ffi::GSTimeSeriesProperties,
+}
+
+impl GsTimeSeriesProperties {
+    pub fn new(properties: &TimeSeriesProperties) -> GsTimeSeriesProperties {
+        let column_names: Vec<CString> = properties
+            .compression_list
+            .iter()
+            .map(|compression| {
+                CString::new(compression.column_name.as_str())
+                    .expect("Error convert String to CString")
+            })
+            .collect();
+        let mut compression_list: Vec<ffi::GSColumnCompression> = properties
+            .compression_list
+            .iter()
+            .zip(&column_names)
+            .map(|(compression, name)| ffi::GSColumnCompression {
+                columnName: name.as_ptr(),
+                relative: compression.relative as i8,
+                rate: compression.rate,
+                span: compression.span,
+                width: compression.width,
+            })
+            .collect();
+        let properties = ffi::GSTimeSeriesProperties {
+            rowExpirationTime: properties.row_expiration_time,
+            rowExpirationTimeUnit: properties.row_expiration_time_unit.clone() as i32,
+            compressionWindowSize: properties.compression_window_size,
+            compressionWindowSizeUnit: properties.compression_window_size_unit.clone() as i32,
+            compressionMethod: properties.compression_method as i32,
+            compressionListSize: compression_list.len() as ffi::size_t,
+            compressionList: if compression_list.is_empty() {
+                ptr::null_mut()
+            } else {
+                compression_list.as_mut_ptr()
+            },
+            expirationDivisionCount: properties.expiration_division_count,
+        };
+        GsTimeSeriesProperties {
+            _column_names: column_names,
+            _compression_list: compression_list,
+            _properties: properties,
+        }
+    }
+
+    // Valid while self is alive
+    pub fn as_ptr(&self) -> *const ffi::GSTimeSeriesProperties {
+        &self._properties
+    }
+}
@@ -0,0 +1,188 @@
+/*
+    Copyright (c) 2022 TOSHIBA Digital Solutions Corporation.
+
+    Licensed under the Apache License, Version 2.0 (the "License");
+    you may not use this file except in compliance with the License.
+    You may obtain a copy of the License at
+
+        http://www.apache.org/licenses/LICENSE-2.0
+
+    Unless required by applicable law or agreed to in writing, software
+    distributed under the License is distributed on an "AS IS" BASIS,
+    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+    See the License for the specific language governing permissions and
+    limitations under the License.
+*/
+
+extern crate griddb_sys as ffi;
+
+use crate::griddb::Type::*;
+use crate::griddb::Util::*;
+use crate::num_to_enum;
+use std::ffi::CStr;
+use std::ffi::CString;
+use std::fmt;
+use std::ptr;
+
+// Class TriggerInfo: notification sent to uri when rows of the container are
+// put or deleted. The jms_* fields, user and password are only used by JMS
+#[derive(Clone, PartialEq, Eq)]
+pub struct TriggerInfo {
+    pub name: String,
+    pub trigger_type: TriggerType,
+    pub uri: String,
+    pub event_type_list: Vec<TriggerEventType>,
+    // Columns whose values are sent with the notification
+    pub column_list: Vec<String>,
+    pub jms_destination_type: Option<String>,
+    pub jms_destination_name: Option<String>,
+    pub user: Option<String>,
+    pub password: Option<String>,
+}
+
+impl TriggerInfo {
+    pub fn rest(name: &str, uri: &str, event_type_list: &[TriggerEventType]) -> TriggerInfo {
+        TriggerInfo {
+            name: name.to_string(),
+            trigger_type: TriggerType::Rest,
+            uri: uri.to_string(),
+            event_type_list: event_type_list.to_vec(),
+            column_list: vec![],
+            jms_destination_type: None,
+            jms_destination_name: None,
+            user: None,
+            password: None,
+        }
+    }
+
+    // destination_type is "queue" or "topic"
+    pub fn jms(
+        name: &str,
+        uri: &str,
+        event_type_list: &[TriggerEventType],
+        destination_type: &str,
+        destination_name: &str,
+    ) -> TriggerInfo {
+        TriggerInfo {
+            trigger_type: TriggerType::Jms,
+            jms_destination_type: Some(destination_type.to_string()),
+            jms_destination_name: Some(destination_name.to_string()),
+            ..TriggerInfo::rest(name, uri, event_type_list)
+        }
+    }
+
+    // Read a trigger returned by the C API
+    pub fn from_gs(info: &ffi::GSTriggerInfo) -> TriggerInfo {
+        let mut column_list = vec![];
+        let mut event_type_list = vec![];
+        for event_type in [TriggerEventType::Put, TriggerEventType::Delete] {
+            if info.eventTypeFlags & event_type as i32 != 0 {
+                event_type_list.push(event_type);
+            }
+        }
+        unsafe {
+            if info.columnCount > 0 {
+                for column in std::slice::from_raw_parts(info.columnSet, info.columnCount as usize)
+                {
+                    column_list.push(CStr::from_ptr(*column).to_string_lossy().into_owned());
+                }
+            }
+        }
+        TriggerInfo {
+            name: Util::to_string(info.name).unwrap_or_default(),
+            trigger_type: num_to_enum!(
+                info.type_ => TriggerType<i32> { Rest, Jms };
+                TriggerType::Rest
+            ),
+            uri: Util::to_string(info.uri).unwrap_or_default(),
+            event_type_list,
+            column_list,
+            jms_destination_type: Util::to_string(info.jmsDestinationType),
+            jms_destination_name: Util::to_string(info.jmsDestinationName),
+            user: Util::to_string(info.user),
+            password: Util::to_string(info.password),
+        }
+    }
+}
+
+// password is redacted so that it does not end in logs
+impl fmt::Debug for TriggerInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TriggerInfo")
+            .field("name", &self.name)
+            .field("trigger_type", &self.trigger_type)
+            .field("uri", &self.uri)
+            .field("event_type_list", &self.event_type_list)
+            .field("column_list", &self.column_list)
+            .field("jms_destination_type", &self.jms_destination_type)
+            .field("jms_destination_name", &self.jms_destination_name)
+            .field("user", &self.user)
+            .field("password", &self.password.as_ref().map(|_| "***"))
+            .finish()
+    }
+}
+
+fn to_c_string(value: &str) -> CString {
+    CString::new(value).expect("Error convert String to CString")
+}
+
+// GSTriggerInfo with the strings it points to
+pub struct GsTriggerInfo {
+    _name: CString,
+    _trigger_type: ffi::GSTriggerType,
+    _uri: CString,
+    _event_type_flags: ffi::GSTriggerEventTypeFlags,
+    _column_list: Vec<CString>,
+    _column_ptrs: Vec<*const ffi::GSChar>,
+    _jms_destination_type: Option<CString>,
+    _jms_destination_name: Option<CString>,
+    _user: Option<CString>,
+    _password: Option<CString>,
+}
+
+impl GsTriggerInfo {
+    pub fn new(info: &TriggerInfo) -> GsTriggerInfo {
+        let column_list: Vec<CString> = info
+            .column_list
+            .iter()
+            .map(|column| to_c_string(column))
+            .collect();
+        GsTriggerInfo {
+            _name: to_c_string(&info.name),
+            _trigger_type: info.trigger_type as ffi::GSTriggerType,
+            _uri: to_c_string(&info.uri),
+            _event_type_flags: info
+                .event_type_list
+                .iter()
+                .fold(0, |flags, event_type| flags | *event_type as i32),
+            _column_ptrs: column_list.iter().map(|column| column.as_ptr()).collect(),
+            _column_list: column_list,
+            _jms_destination_type: info.jms_destination_type.as_deref().map(to_c_string),
+            _jms_destination_name: info.jms_destination_name.as_deref().map(to_c_string),
+            _user: info.user.as_deref().map(to_c_string),
+            _password: info.password.as_deref().map(to_c_string),
+        }
+    }
+
+    // Valid while self is alive
+    pub fn unwrap(&self) -> ffi::GSTriggerInfo {
+        let as_ptr =
+            |value: &Option<CString>| value.as_ref().map_or(ptr::null(), |value| value.as_ptr());
+        ffi::GSTriggerInfo {
+            name: self._name.as_ptr(),
+            type_: self._trigger_type,
+            uri: self._uri.as_ptr(),
+            eventTypeFlags: self._event_type_flags,
+            columnSet: if self._column_ptrs.is_empty() {
+                ptr::null()
+            } else {
+                self._column_ptrs.as_ptr()
+            },
+            columnCount: self._column_ptrs.len() as ffi::size_t,
+            jmsDestinationType: as_ptr(&self._jms_destination_type),
+            jmsDestinationName: as_ptr(&self._jms_destination_name),
+            user: as_ptr(&self._user),
+            password: as_ptr(&self._password),
+        }
+    }
+}
@@ -35,13 +35,23 @@ pub enum Type {
     Timestamp,
     Geometry,
     Blob,
+    StringArray,
+    BoolArray,
+    ByteArray,
+    ShortArray,
+    IntegerArray,
+    LongArray,
+    FloatArray,
+    DoubleArray,
+    TimestampArray,
 }
 
 #[repr(i32)]
-#[derive(PartialEq, Eq, Debug, Clone)]
+#[derive(PartialEq, Eq, Copy, Debug, Clone)]
 pub enum IndexType {
     Default = -1,
     Tree = 1,
+    Hash = 2,
     Spatial = 4,
 }
 
@@ -57,6 +67,74 @@ pub enum TimeUnit {
     MilliSecond = 6,
 }
 
+#[repr(i32)]
+#[derive(PartialEq, Eq, Copy, Debug, Clone)]
+pub enum TimeOperator {
+    // Latest row at or before the base time
+    Previous = 0,
+    PreviousOnly = 1,
+    // Earliest row at or after the base time
+    Next = 2,
+    NextOnly = 3,
+}
+
+#[repr(i32)]
+#[derive(PartialEq, Eq, Copy, Debug, Clone)]
+pub enum QueryOrder {
+    Ascending = 0,
+    Descending = 1,
+}
+
+#[repr(i32)]
+#[derive(PartialEq, Eq, Copy, Debug, Clone)]
+pub enum InterpolationMode {
+    // Linear for numeric columns, value of the previous row for the others
+    LinearOrPrevious = 0,
+    // Null for every column except the row key
+    Empty = 1,
+}
+
+#[repr(i32)]
+#[derive(PartialEq, Eq, Copy, Debug, Clone)]
+pub enum Aggregation {
+    Minimum = 0,
+    Maximum = 1,
+    Total = 2,
+    Average = 3,
+    Variance = 4,
+    StandardDeviation = 5,
+    Count = 6,
+    WeightedAverage = 7,
+}
+
+#[repr(i32)]
+#[derive(PartialEq, Eq, Copy, Debug, Clone)]
+pub enum GeometryOperator {
+    Intersect = 0,
+}
+
+#[repr(i32)]
+#[derive(PartialEq, Eq, Copy, Debug, Clone)]
+pub enum TriggerType {
+    Rest = 0,
+    Jms = 1,
+}
+
+#[repr(i32)]
+#[derive(PartialEq, Eq, Copy, Debug, Clone)]
+pub enum TriggerEventType {
+    Put = 1,
+    Delete = 2,
+}
+
+#[repr(i32)]
+#[derive(PartialEq, Eq, Copy, Debug, Clone)]
+pub enum CompressionMethod {
+    No = 0,
+    Ss = 1,
+    Hi = 2,
+}
+
 #[repr(i32)]
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub enum RowSetType {
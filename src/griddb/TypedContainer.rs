@@ -0,0 +1,162 @@
+/*
+    Copyright (c) 2022 TOSHIBA Digital Solutions Corporation.
+
+    Licensed under the Apache License, Version 2.0 (the "License");
+    you may not use this file except in compliance with the License.
+    You may obtain a copy of the License at
+
+        http://www.apache.org/licenses/LICENSE-2.0
+
+    Unless required by applicable law or agreed to in writing, software
+    distributed under the License is distributed on an "AS IS" BASIS,
+    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+    See the License for the specific language governing permissions and
+    limitations under the License.
+*/
+
+use crate::griddb::Const::*;
+use crate::griddb::Container::*;
+use crate::griddb::Error::*;
+use crate::griddb::GridRow::*;
+use crate::griddb::Query::*;
+use crate::griddb::RowSet::*;
+use crate::griddb::Value::Value;
+use std::marker::PhantomData;
+
+// Class TypedContainer: container whose rows are mapped to T
+pub struct TypedContainer<T: GridRow> {
+    _container: Container,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: GridRow> TypedContainer<T> {
+    // Fails when the schema of container does not match T
+    pub fn new(container: Container) -> Result<TypedContainer<T>, GridDbError> {
+        check_schema::<T>(&container)?;
+        Ok(TypedContainer {
+            _container: container,
+            _phantom: PhantomData,
+        })
+    }
+
+    // Untyped container, for operations without a typed counterpart
+    pub fn container(&self) -> &Container {
+        &self._container
+    }
+
+    pub fn put(&self, row: &T) -> Result<(), GridDbError> {
+        self._container.put(row.to_row())
+    }
+
+    pub fn put_rows(&self, rows: &[T]) -> Result<(), GridDbError> {
+        self._container
+            .put_rows(rows.iter().map(|row| row.to_row()).collect())
+    }
+
+    // None when no row has this key
+    pub fn get(&self, key: &T::Key) -> Result<Option<T>, GridDbError>
+    where
+        T::Key: Clone + Into<Value>,
+    {
+        let row = self._container.get_by_key(&key.clone().into())?;
+        if row.is_empty() {
+            return Ok(None);
+        }
+        T::from_row(row).map(Some)
+    }
+
+    pub fn query(&self, query: &str) -> Result<TypedQuery<T>, GridDbError> {
+        Ok(TypedQuery {
+            _query: self._container.query(query)?,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+// Class TypedQuery
+pub struct TypedQuery<T: GridRow> {
+    _query: Query,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: GridRow> TypedQuery<T> {
+    pub fn fetch(&self) -> Result<TypedRowSet<T>, GridDbError> {
+        Ok(TypedRowSet {
+            _row_set: self._query.fetch()?,
+            _phantom: PhantomData,
+        })
+    }
+
+    // Untyped query, for fetch options
+    pub fn query(&self) -> &Query {
+        &self._query
+    }
+}
+
+// Class TypedRowSet: iterates the fetched rows as T
+pub struct TypedRowSet<T: GridRow> {
+    _row_set: RowSet,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: GridRow> TypedRowSet<T> {
+    pub fn size(&self) -> i32 {
+        self._row_set.size
+    }
+}
+
+impl<T: GridRow> Iterator for TypedRowSet<T> {
+    type Item = Result<T, GridDbError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self._row_set.next()
+            .map(|row| row.and_then(|row| T::from_row(row.into_values())))
+    }
+}
+
+fn schema_error(message: String) -> GridDbError {
+    GridDbError::new(ERROR_CONVERT_DATA, &message)
+}
+
+// Compare the columns of container opened on the server with T
+fn check_schema<T: GridRow>(container: &Container) -> Result<(), GridDbError> {
+    let actual = container.column_info_list();
+    let expected = T::columns();
+    if actual.len() != expected.len() {
+        return Err(schema_error(format!(
+            "Container has {} columns but {} are expected",
+            actual.len(),
+            expected.len()
+        )));
+    }
+    if container.row_key() != T::ROW_KEY {
+        return Err(schema_error(format!(
+            "Container row key is {} but {} is expected",
+            container.row_key(),
+            T::ROW_KEY
+        )));
+    }
+    for (pos, ((name, column_type, option), (expected_name, expected_type, expected_option))) in
+        actual.iter().zip(expected).enumerate()
+    {
+        if !name.eq_ignore_ascii_case(expected_name) {
+            return Err(schema_error(format!(
+                "Column {} is {} but {} is expected",
+                pos, name, expected_name
+            )));
+        }
+        if *column_type != expected_type {
+            return Err(schema_error(format!(
+                "Column {} is {:?} but {:?} is expected",
+                name, column_type, expected_type
+            )));
+        }
+        if *option != expected_option {
+            return Err(schema_error(format!(
+                "Column {} is {:?} but {:?} is expected",
+                name, option, expected_option
+            )));
+        }
+    }
+    Ok(())
+}
@@ -16,10 +16,18 @@
 
 extern crate griddb_sys as ffi;
 
+use crate::griddb::Const::*;
+use crate::griddb::ContainerInfo::*;
+use crate::griddb::Error::*;
+use crate::griddb::Geometry::*;
 use crate::griddb::Type::*;
 use crate::griddb::Value::*;
 use convert_case::{Case, Casing};
+use std::ffi::c_void;
+use std::ffi::CStr;
 use std::ffi::CString;
+use std::os::raw::c_char;
+use std::ptr;
 use std::vec::Vec;
 use std::{slice, str};
 
@@ -37,6 +45,20 @@ macro_rules! num_to_enum {
 
 pub struct Util;
 impl Util {
+    // Row locks are released at commit, so they need a manual transaction
+    pub fn check_manual_commit(auto_commit: bool, operation: &str) -> Result<(), GridDbError> {
+        if auto_commit {
+            return Err(GridDbError::new(
+                ERROR_INVALID_STATE,
+                &format!(
+                    "{}() needs auto-commit to be disabled with set_auto_commit(false)",
+                    operation
+                ),
+            ));
+        }
+        Ok(())
+    }
+
     // Prepare properties for function getStore()
     pub fn tup_to_properties(
         properties: Vec<(&str, &str)>,
@@ -61,14 +83,117 @@ impl Util {
         result
     }
 
+    // Check the values of a row against the column types and nullability
+    pub fn check_row(
+        fields: &[Value],
+        type_list: &[Type],
+        nullable_list: &[bool],
+    ) -> Result<(), GridDbError> {
+        if fields.len() != type_list.len() {
+            return Err(GridDbError::new(
+                ERROR_CONVERT_DATA,
+                &format!(
+                    "Row has {} values but the container has {} columns",
+                    fields.len(),
+                    type_list.len()
+                ),
+            ));
+        }
+        for (pos, e) in fields.iter().enumerate() {
+            if e.is_null() && !nullable_list[pos] {
+                return Err(GridDbError::new(
+                    ERROR_CONVERT_DATA,
+                    &format!("Value at column {} is NULL but the column is NOT NULL", pos),
+                ));
+            }
+            match e.data_type() {
+                Some(data_type) if data_type != type_list[pos] => {
+                    return Err(GridDbError::new(
+                        ERROR_CONVERT_DATA,
+                        &format!(
+                            "Value at column {} is {:?} but the column type is {:?}",
+                            pos, data_type, type_list[pos]
+                        ),
+                    ));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    // Copy a string owned by the C client, None for a null pointer
     #[allow(clippy::not_unsafe_ptr_arg_deref)]
-    pub fn get_row_data(row: *mut GSRow, vector: &mut Vec<Value>, typeList: &[Type]) -> i32 {
+    pub fn to_string(value: *const c_char) -> Option<String> {
+        if value.is_null() {
+            return None;
+        }
+        let string;
+        unsafe {
+            string = CStr::from_ptr(value).to_string_lossy().into_owned();
+        }
+        Some(string)
+    }
+
+    // Set every field of row
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn bind_row(fields: &[Value], row: *mut GSRow) -> Result<(), GridDbError> {
+        for (pos, e) in fields.iter().enumerate() {
+            GridDbError::check(e.bind(pos as i32, row), row as *mut c_void)?;
+        }
+        Ok(())
+    }
+
+    // Row key object for the columns of key_info, closed with gsCloseRow
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn create_row_key(
+        store: *mut ffi::GSGridStore,
+        key_info: &ContainerInfo,
+        key: &[Value],
+    ) -> Result<*mut ffi::GSRowKey, GridDbError> {
+        let type_list: Vec<Type> = key_info
+            .column_info_list
+            .iter()
+            .map(|column| column.1)
+            .collect();
+        let nullable_list = vec![false; type_list.len()];
+        Util::check_row(key, &type_list, &nullable_list)?;
+        let gs_info = key_info.unwrap();
+        let mut gs_key: *mut ffi::GSRowKey = ptr::null_mut();
+        let ret;
+        unsafe {
+            ret = ffi::gsCreateRowKeyByStore(store, &gs_info, &mut gs_key);
+        }
+        GridDbError::check(ret, store as *mut c_void)?;
+        if let Err(error) = Util::bind_row(key, gs_key) {
+            unsafe {
+                ffi::gsCloseRow(&mut gs_key);
+            }
+            return Err(error);
+        }
+        Ok(gs_key)
+    }
+
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn get_row_data(
+        row: *mut GSRow,
+        vector: &mut Vec<Value>,
+        typeList: &[Type],
+    ) -> Result<(), GridDbError> {
         for (pos, fieldType) in typeList.iter().enumerate() {
+            let (ret, is_null) = get_row_field_null(row, pos);
+            if ret != ffi::GS_RESULT_OK as i32 {
+                return Err(GridDbError::from_resource(ret, row as *mut c_void));
+            }
+            if is_null {
+                (*vector).push(Value::Null);
+                continue;
+            }
             match *fieldType {
                 Type::String => {
                     let (ret, str) = get_row_field_as_str(row, pos);
                     if ret != ffi::GS_RESULT_OK as i32 {
-                        return ret;
+                        return Err(GridDbError::from_resource(ret, row as *mut c_void));
                     }
                     let value: Value = Value::new(str.to_string());
                     (*vector).push(value);
@@ -76,7 +201,7 @@ impl Util {
                 Type::Bool => {
                     let (ret, boolValue) = get_row_field_as_bool(row, pos);
                     if ret != ffi::GS_RESULT_OK as i32 {
-                        return ret;
+                        return Err(GridDbError::from_resource(ret, row as *mut c_void));
                     }
                     let value: Value = Value::new(boolValue);
                     (*vector).push(value);
@@ -84,7 +209,7 @@ impl Util {
                 Type::Byte => {
                     let (ret, rawdata) = get_row_field_as_byte(row, pos);
                     if ret != ffi::GS_RESULT_OK as i32 {
-                        return ret;
+                        return Err(GridDbError::from_resource(ret, row as *mut c_void));
                     }
                     let value: Value = Value::new(rawdata);
                     (*vector).push(value);
@@ -92,7 +217,7 @@ impl Util {
                 Type::Short => {
                     let (ret, rawdata) = get_row_field_as_short(row, pos);
                     if ret != ffi::GS_RESULT_OK as i32 {
-                        return ret;
+                        return Err(GridDbError::from_resource(ret, row as *mut c_void));
                     }
                     let value: Value = Value::new(rawdata);
                     (*vector).push(value);
@@ -100,7 +225,7 @@ impl Util {
                 Type::Integer => {
                     let (ret, rawdata) = get_row_field_as_interger(row, pos);
                     if ret != ffi::GS_RESULT_OK as i32 {
-                        return ret;
+                        return Err(GridDbError::from_resource(ret, row as *mut c_void));
                     }
                     let value: Value = Value::new(rawdata);
                     (*vector).push(value);
@@ -108,7 +233,7 @@ impl Util {
                 Type::Long => {
                     let (ret, longValue) = get_row_field_as_long(row, pos);
                     if ret != ffi::GS_RESULT_OK as i32 {
-                        return ret;
+                        return Err(GridDbError::from_resource(ret, row as *mut c_void));
                     }
                     let value: Value = Value::new(longValue);
                     (*vector).push(value);
@@ -116,7 +241,7 @@ impl Util {
                 Type::Float => {
                     let (ret, rawdata) = get_row_field_as_float(row, pos);
                     if ret != ffi::GS_RESULT_OK as i32 {
-                        return ret;
+                        return Err(GridDbError::from_resource(ret, row as *mut c_void));
                     }
                     let value: Value = Value::new(rawdata);
                     (*vector).push(value);
@@ -124,7 +249,7 @@ impl Util {
                 Type::Double => {
                     let (ret, rawdata) = get_row_field_as_double(row, pos);
                     if ret != ffi::GS_RESULT_OK as i32 {
-                        return ret;
+                        return Err(GridDbError::from_resource(ret, row as *mut c_void));
                     }
                     let value: Value = Value::new(rawdata);
                     (*vector).push(value);
@@ -132,27 +257,133 @@ impl Util {
                 Type::Timestamp => {
                     let (ret, rawdata) = get_row_field_as_timestamp(row, pos);
                     if ret != ffi::GS_RESULT_OK as i32 {
-                        return ret;
+                        return Err(GridDbError::from_resource(ret, row as *mut c_void));
                     }
                     let value: Value = Value::new(rawdata);
                     (*vector).push(value);
                 }
-                Type::Geometry => {}
+                Type::Geometry => {
+                    let (ret, wkt) = get_row_field_as_geometry(row, pos);
+                    if ret != ffi::GS_RESULT_OK as i32 {
+                        return Err(GridDbError::from_resource(ret, row as *mut c_void));
+                    }
+                    let value: Value = Value::new(Geometry::from_server_wkt(wkt));
+                    (*vector).push(value);
+                }
                 Type::Blob => {
                     let (ret, rawdata) = get_row_field_as_blob(row, pos);
                     if ret != ffi::GS_RESULT_OK as i32 {
-                        return ret;
+                        return Err(GridDbError::from_resource(ret, row as *mut c_void));
+                    }
+                    let value: Value = Value::new(rawdata);
+                    (*vector).push(value);
+                }
+                Type::StringArray => {
+                    let (ret, rawdata) = get_row_field_as_string_array(row, pos);
+                    if ret != ffi::GS_RESULT_OK as i32 {
+                        return Err(GridDbError::from_resource(ret, row as *mut c_void));
+                    }
+                    let value: Value = Value::new(rawdata);
+                    (*vector).push(value);
+                }
+                Type::BoolArray => {
+                    let (ret, rawdata) =
+                        get_row_field_as_array(row, pos, ffi::gsGetRowFieldAsBoolArray);
+                    if ret != ffi::GS_RESULT_OK as i32 {
+                        return Err(GridDbError::from_resource(ret, row as *mut c_void));
+                    }
+                    let value: Value = Value::new(
+                        rawdata
+                            .iter()
+                            .map(|b| *b != ffi::GS_FALSE as i8)
+                            .collect::<Vec<bool>>(),
+                    );
+                    (*vector).push(value);
+                }
+                Type::ByteArray => {
+                    let (ret, rawdata) =
+                        get_row_field_as_array(row, pos, ffi::gsGetRowFieldAsByteArray);
+                    if ret != ffi::GS_RESULT_OK as i32 {
+                        return Err(GridDbError::from_resource(ret, row as *mut c_void));
+                    }
+                    let value: Value = Value::new(rawdata);
+                    (*vector).push(value);
+                }
+                Type::ShortArray => {
+                    let (ret, rawdata) =
+                        get_row_field_as_array(row, pos, ffi::gsGetRowFieldAsShortArray);
+                    if ret != ffi::GS_RESULT_OK as i32 {
+                        return Err(GridDbError::from_resource(ret, row as *mut c_void));
+                    }
+                    let value: Value = Value::new(rawdata);
+                    (*vector).push(value);
+                }
+                Type::IntegerArray => {
+                    let (ret, rawdata) =
+                        get_row_field_as_array(row, pos, ffi::gsGetRowFieldAsIntegerArray);
+                    if ret != ffi::GS_RESULT_OK as i32 {
+                        return Err(GridDbError::from_resource(ret, row as *mut c_void));
+                    }
+                    let value: Value = Value::new(rawdata);
+                    (*vector).push(value);
+                }
+                Type::LongArray => {
+                    let (ret, rawdata) =
+                        get_row_field_as_array(row, pos, ffi::gsGetRowFieldAsLongArray);
+                    if ret != ffi::GS_RESULT_OK as i32 {
+                        return Err(GridDbError::from_resource(ret, row as *mut c_void));
+                    }
+                    let value: Value = Value::new(rawdata);
+                    (*vector).push(value);
+                }
+                Type::FloatArray => {
+                    let (ret, rawdata) =
+                        get_row_field_as_array(row, pos, ffi::gsGetRowFieldAsFloatArray);
+                    if ret != ffi::GS_RESULT_OK as i32 {
+                        return Err(GridDbError::from_resource(ret, row as *mut c_void));
+                    }
+                    let value: Value = Value::new(rawdata);
+                    (*vector).push(value);
+                }
+                Type::DoubleArray => {
+                    let (ret, rawdata) =
+                        get_row_field_as_array(row, pos, ffi::gsGetRowFieldAsDoubleArray);
+                    if ret != ffi::GS_RESULT_OK as i32 {
+                        return Err(GridDbError::from_resource(ret, row as *mut c_void));
                     }
                     let value: Value = Value::new(rawdata);
                     (*vector).push(value);
                 }
-            }            
+                Type::TimestampArray => {
+                    let (ret, rawdata) =
+                        get_row_field_as_array(row, pos, ffi::gsGetRowFieldAsTimestampArray);
+                    if ret != ffi::GS_RESULT_OK as i32 {
+                        return Err(GridDbError::from_resource(ret, row as *mut c_void));
+                    }
+                    let value: Value = Value::new(
+                        rawdata
+                            .iter()
+                            .map(|t| Timestamp { value: *t })
+                            .collect::<Vec<Timestamp>>(),
+                    );
+                    (*vector).push(value);
+                }
+            }
         }
-        ffi::GS_RESULT_OK as i32
+        Ok(())
     }
 }
 
 // get field data for row
+fn get_row_field_null(row: *mut GSRow, column: usize) -> (i32, bool) {
+    let mut null_value: ffi::GSBool = ffi::GS_FALSE as i8;
+    let ret: i32;
+    unsafe {
+        ret = ffi::gsGetRowFieldNull(row, column as i32, &mut null_value);
+    }
+    (ret, null_value == ffi::GS_TRUE as i8)
+}
+
 fn get_row_field_as_str(row: *mut GSRow, column: usize) -> (i32, String) {
     let void_ptr;
     unsafe {
@@ -163,12 +394,73 @@ fn get_row_field_as_str(row: *mut GSRow, column: usize) -> (i32, String) {
     let ret: i32;
     unsafe {
         ret = ffi::gsGetRowFieldAsString(row, column as i32, &mut result);
-        tmp_string = std::ffi::CStr::from_ptr(result as *const i8);
+        tmp_string = std::ffi::CStr::from_ptr(result);
         ffi::free(void_ptr as *mut _);
     }
     (ret, tmp_string.to_string_lossy().into_owned())
 }
 
+fn get_row_field_as_geometry(row: *mut GSRow, column: usize) -> (i32, String) {
+    let mut result: *const i8 = ptr::null();
+    let ret: i32;
+    unsafe {
+        ret = ffi::gsGetRowFieldAsGeometry(row, column as i32, &mut result);
+    }
+    if ret != ffi::GS_RESULT_OK as i32 || result.is_null() {
+        return (ret, String::new());
+    }
+    let tmp_string;
+    unsafe {
+        tmp_string = std::ffi::CStr::from_ptr(result);
+    }
+    (ret, tmp_string.to_string_lossy().into_owned())
+}
+
+fn get_row_field_as_string_array(row: *mut GSRow, column: usize) -> (i32, Vec<String>) {
+    let mut result: *const *const ffi::GSChar = ptr::null();
+    let mut size: ffi::size_t = 0;
+    let ret: i32;
+    unsafe {
+        ret = ffi::gsGetRowFieldAsStringArray(row, column as i32, &mut result, &mut size);
+    }
+    if ret != ffi::GS_RESULT_OK as i32 || result.is_null() {
+        return (ret, Vec::new());
+    }
+    let mut vec_result: Vec<String> = Vec::with_capacity(size as usize);
+    unsafe {
+        for &elem in slice::from_raw_parts(result, size as usize) {
+            vec_result.push(
+                std::ffi::CStr::from_ptr(elem)
+                    .to_string_lossy()
+                    .into_owned(),
+            );
+        }
+    }
+    (ret, vec_result)
+}
+
+// get array field data with one of gsGetRowFieldAs*Array
+fn get_row_field_as_array<T: Copy>(
+    row: *mut GSRow,
+    column: usize,
+    getter: unsafe extern "C" fn(*mut GSRow, i32, *mut *const T, *mut ffi::size_t) -> ffi::GSResult,
+) -> (i32, Vec<T>) {
+    let mut result: *const T = ptr::null();
+    let mut size: ffi::size_t = 0;
+    let ret: i32;
+    unsafe {
+        ret = getter(row, column as i32, &mut result, &mut size);
+    }
+    if ret != ffi::GS_RESULT_OK as i32 || result.is_null() {
+        return (ret, Vec::new());
+    }
+    let vec_result;
+    unsafe {
+        vec_result = slice::from_raw_parts(result, size as usize).to_vec();
+    }
+    (ret, vec_result)
+}
+
 fn get_row_field_as_bool(row: *mut GSRow, column: usize) -> (i32, bool) {
     let bool_v: *mut ffi::GSBool;
     let value_result;
@@ -295,7 +587,67 @@ fn get_row_field_as_timestamp(row: *mut GSRow, column: usize) -> (i32, Timestamp
     }
 
     // Return DateTime
-    (ret, Timestamp {
-        value: value_result,
-    })
+    (
+        ret,
+        Timestamp {
+            value: value_result,
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TYPE_LIST: [Type; 3] = [Type::String, Type::Long, Type::Double];
+    const NULLABLE_LIST: [bool; 3] = [false, false, true];
+
+    #[test]
+    fn check_row_accepts_matching_row() {
+        let row = vec![
+            Value::Str("name01".to_string()),
+            Value::Long(100),
+            Value::Double(1.5),
+        ];
+        assert!(Util::check_row(&row, &TYPE_LIST, &NULLABLE_LIST).is_ok());
+    }
+
+    #[test]
+    fn check_row_null_only_in_nullable_column() {
+        let row = vec![
+            Value::Str("name01".to_string()),
+            Value::Long(100),
+            Value::Null,
+        ];
+        assert!(Util::check_row(&row, &TYPE_LIST, &NULLABLE_LIST).is_ok());
+
+        let row = vec![Value::Str("name01".to_string()), Value::Null, Value::Null];
+        let error = Util::check_row(&row, &TYPE_LIST, &NULLABLE_LIST).unwrap_err();
+        assert_eq!(error.code, ERROR_CONVERT_DATA);
+        assert_eq!(
+            error.message(),
+            "Value at column 1 is NULL but the column is NOT NULL"
+        );
+    }
+
+    #[test]
+    fn check_row_rejects_type_and_length_mismatch() {
+        let row = vec![
+            Value::Str("name01".to_string()),
+            Value::Integer(100),
+            Value::Null,
+        ];
+        let error = Util::check_row(&row, &TYPE_LIST, &NULLABLE_LIST).unwrap_err();
+        assert_eq!(
+            error.message(),
+            "Value at column 1 is Integer but the column type is Long"
+        );
+
+        let row = vec![Value::Str("name01".to_string())];
+        let error = Util::check_row(&row, &TYPE_LIST, &NULLABLE_LIST).unwrap_err();
+        assert_eq!(
+            error.message(),
+            "Row has 1 values but the container has 3 columns"
+        );
+    }
 }
@@ -15,6 +15,8 @@
 */
 
 use crate::griddb::Const::*;
+use crate::griddb::Error::*;
+pub use crate::griddb::Geometry::{Coordinate, Geometry};
 use crate::griddb::Type::*;
 use std::ffi::CString;
 extern crate griddb_sys as ffi;
@@ -25,17 +27,10 @@ pub struct Timestamp {
     pub value: i64,
 }
 
-#[derive(Debug, Clone)]
-pub struct Geometry {
-    value: String,
-}
-
-pub struct Null {}
-
 #[derive(Debug, Clone)]
 //Support map data when put/get row
 pub enum Value {
-    // Null,
+    Null,
     Str(String),
     Bool(bool),
     Byte(i8),
@@ -47,6 +42,15 @@ pub enum Value {
     Timestamp(Timestamp),
     Geometry(Geometry),
     Blob(Vec<u8>),
+    StringArray(Vec<String>),
+    BoolArray(Vec<bool>),
+    ByteArray(Vec<i8>),
+    ShortArray(Vec<i16>),
+    IntegerArray(Vec<i32>),
+    LongArray(Vec<i64>),
+    FloatArray(Vec<f32>),
+    DoubleArray(Vec<f64>),
+    TimestampArray(Vec<Timestamp>),
 }
 
 impl Value {
@@ -59,9 +63,10 @@ impl Value {
 
     #[inline]
     #[must_use]
-    pub fn data_type(&self) -> Type {
-        match *self {
-            // Value::Null => Type::Null,
+    // Column type of the value, None for NULL which fits a column of any type
+    pub fn data_type(&self) -> Option<Type> {
+        let data_type = match *self {
+            Value::Null => return None,
             Value::Str(_) => Type::String,
             Value::Bool(_) => Type::Bool,
             Value::Byte(_) => Type::Byte,
@@ -73,40 +78,184 @@ impl Value {
             Value::Timestamp(_) => Type::Timestamp,
             Value::Geometry(_) => Type::Geometry,
             Value::Blob(_) => Type::Blob,
+            Value::StringArray(_) => Type::StringArray,
+            Value::BoolArray(_) => Type::BoolArray,
+            Value::ByteArray(_) => Type::ByteArray,
+            Value::ShortArray(_) => Type::ShortArray,
+            Value::IntegerArray(_) => Type::IntegerArray,
+            Value::LongArray(_) => Type::LongArray,
+            Value::FloatArray(_) => Type::FloatArray,
+            Value::DoubleArray(_) => Type::DoubleArray,
+            Value::TimestampArray(_) => Type::TimestampArray,
+        };
+        Some(data_type)
+    }
+
+    // Type for error messages, "Null" for NULL
+    pub(crate) fn type_name(&self) -> String {
+        match self.data_type() {
+            Some(data_type) => format!("{:?}", data_type),
+            None => "Null".to_string(),
         }
     }
-}
 
-impl From<String> for Geometry {
-    fn from(item: String) -> Self {
-        Geometry { value: item }
+    #[inline]
+    #[must_use]
+    pub fn is_null(&self) -> bool {
+        matches!(*self, Value::Null)
     }
-}
 
-impl From<Value> for Geometry {
-    fn from(_item: Value) -> Geometry {
-        match _item {
-            Value::Geometry(val) => val,
-            _ => Geometry {
-                value: String::from(""),
-            },
+    // Borrowed accessors, None when the variant does not match
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::Str(val) => Some(val.as_str()),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Bool(val) => Some(*val),
+            _ => None,
+        }
+    }
+
+    pub fn as_i8(&self) -> Option<i8> {
+        match self {
+            Value::Byte(val) => Some(*val),
+            _ => None,
+        }
+    }
+
+    pub fn as_i16(&self) -> Option<i16> {
+        match self {
+            Value::Short(val) => Some(*val),
+            _ => None,
+        }
+    }
+
+    pub fn as_i32(&self) -> Option<i32> {
+        match self {
+            Value::Integer(val) => Some(*val),
+            _ => None,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Value::Long(val) => Some(*val),
+            _ => None,
+        }
+    }
+
+    pub fn as_f32(&self) -> Option<f32> {
+        match self {
+            Value::Float(val) => Some(*val),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Double(val) => Some(*val),
+            _ => None,
+        }
+    }
+
+    pub fn as_timestamp(&self) -> Option<Timestamp> {
+        match self {
+            Value::Timestamp(val) => Some(*val),
+            _ => None,
+        }
+    }
+
+    pub fn as_geometry(&self) -> Option<&Geometry> {
+        match self {
+            Value::Geometry(val) => Some(val),
+            _ => None,
+        }
+    }
+
+    pub fn as_blob(&self) -> Option<&[u8]> {
+        match self {
+            Value::Blob(val) => Some(val.as_slice()),
+            _ => None,
+        }
+    }
+
+    pub fn as_string_array(&self) -> Option<&[String]> {
+        match self {
+            Value::StringArray(val) => Some(val.as_slice()),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool_array(&self) -> Option<&[bool]> {
+        match self {
+            Value::BoolArray(val) => Some(val.as_slice()),
+            _ => None,
+        }
+    }
+
+    pub fn as_byte_array(&self) -> Option<&[i8]> {
+        match self {
+            Value::ByteArray(val) => Some(val.as_slice()),
+            _ => None,
+        }
+    }
+
+    pub fn as_short_array(&self) -> Option<&[i16]> {
+        match self {
+            Value::ShortArray(val) => Some(val.as_slice()),
+            _ => None,
+        }
+    }
+
+    pub fn as_integer_array(&self) -> Option<&[i32]> {
+        match self {
+            Value::IntegerArray(val) => Some(val.as_slice()),
+            _ => None,
+        }
+    }
+
+    pub fn as_long_array(&self) -> Option<&[i64]> {
+        match self {
+            Value::LongArray(val) => Some(val.as_slice()),
+            _ => None,
+        }
+    }
+
+    pub fn as_float_array(&self) -> Option<&[f32]> {
+        match self {
+            Value::FloatArray(val) => Some(val.as_slice()),
+            _ => None,
+        }
+    }
+
+    pub fn as_double_array(&self) -> Option<&[f64]> {
+        match self {
+            Value::DoubleArray(val) => Some(val.as_slice()),
+            _ => None,
+        }
+    }
+
+    pub fn as_timestamp_array(&self) -> Option<&[Timestamp]> {
+        match self {
+            Value::TimestampArray(val) => Some(val.as_slice()),
+            _ => None,
         }
     }
 }
 
-impl From<String> for Value {
-    fn from(item: String) -> Self {
-        Value::Str(item)
+impl From<Geometry> for Value {
+    fn from(item: Geometry) -> Self {
+        Value::Geometry(item)
     }
 }
 
-impl From<Value> for String {
-    fn from(_item: Value) -> String {
-        // false
-        match _item {
-            Value::Str(val) => val,
-            _ => String::from(""),
-        }
+impl From<String> for Value {
+    fn from(item: String) -> Self {
+        Value::Str(item)
     }
 }
 
@@ -116,142 +265,173 @@ impl From<bool> for Value {
     }
 }
 
-impl From<Value> for bool {
-    fn from(_item: Value) -> bool {
-        // false
-        match _item {
-            Value::Bool(a) => a,
-            _ => false,
-        }
-    }
-}
-
 impl From<i8> for Value {
     fn from(item: i8) -> Self {
         Value::Byte(item)
     }
 }
 
-impl From<Value> for i8 {
-    fn from(_item: Value) -> i8 {
-        // false
-        match _item {
-            Value::Byte(val) => val,
-            _ => ERROR_CONVERT_DATA as i8,
-        }
-    }
-}
-
 impl From<i16> for Value {
     fn from(item: i16) -> Self {
         Value::Short(item)
     }
 }
 
-impl From<Value> for i16 {
-    fn from(_item: Value) -> i16 {
-        // false
-        match _item {
-            Value::Short(val) => val,
-            _ => ERROR_CONVERT_DATA as i16,
-        }
-    }
-}
-
 impl From<i32> for Value {
     fn from(item: i32) -> Self {
         Value::Integer(item)
     }
 }
 
-impl From<Value> for i32 {
-    fn from(_item: Value) -> i32 {
-        // false
-        match _item {
-            Value::Integer(int) => int,
-            _ => ERROR_CONVERT_DATA as i32,
-        }
-    }
-}
-
 impl From<i64> for Value {
     fn from(item: i64) -> Self {
         Value::Long(item)
     }
 }
 
-impl From<Value> for i64 {
-    fn from(_item: Value) -> i64 {
-        // false
-        match _item {
-            Value::Long(val) => val,
-            _ => ERROR_CONVERT_DATA as i64,
-        }
-    }
-}
-
 impl From<f32> for Value {
     fn from(item: f32) -> Self {
         Value::Float(item)
     }
 }
 
-impl From<Value> for f32 {
-    fn from(_item: Value) -> f32 {
-        match _item {
-            Value::Float(val) => val,
-            _ => ERROR_CONVERT_DATA as f32,
-        }
-    }
-}
-
 impl From<f64> for Value {
     fn from(item: f64) -> Self {
         Value::Double(item)
     }
 }
 
-impl From<Value> for f64 {
-    fn from(_item: Value) -> f64 {
-        match _item {
-            Value::Double(val) => val,
-            _ => ERROR_CONVERT_DATA as f64,
-        }
-    }
-}
-
 impl From<Timestamp> for Value {
     fn from(item: Timestamp) -> Self {
         Value::Timestamp(item)
     }
 }
 
-impl From<Value> for Timestamp {
-    fn from(_item: Value) -> Timestamp {
-        match _item {
-            Value::Timestamp(val) => val,
-            _ => Timestamp {
-                value: ERROR_CONVERT_DATA as i64,
-            },
-        }
-    }
-}
-
 impl From<Vec<u8>> for Value {
     fn from(item: Vec<u8>) -> Self {
         Value::Blob(item)
     }
 }
 
-impl From<Value> for Vec<u8> {
-    fn from(_item: Value) -> Vec<u8> {
-        match _item {
-            Value::Blob(val) => val,
-            _ => Vec::new(),
+// Array column values
+macro_rules! impl_array_value {
+    ($($variant:ident($t:ty)),*) => {
+        $(
+            impl From<Vec<$t>> for Value {
+                fn from(item: Vec<$t>) -> Self {
+                    Value::$variant(item)
+                }
+            }
+        )*
+    };
+}
+
+impl_array_value!(
+    StringArray(String),
+    BoolArray(bool),
+    ByteArray(i8),
+    ShortArray(i16),
+    IntegerArray(i32),
+    LongArray(i64),
+    FloatArray(f32),
+    DoubleArray(f64),
+    TimestampArray(Timestamp)
+);
+
+impl<T> From<Option<T>> for Value
+where
+    T: Into<Value>,
+{
+    fn from(item: Option<T>) -> Self {
+        match item {
+            Some(val) => val.into(),
+            None => Value::Null,
         }
     }
 }
 
+fn convert_error(item: &Value, target: &str) -> GridDbError {
+    GridDbError::new(
+        ERROR_CONVERT_DATA,
+        &format!("Cannot convert {} value to {}", item.type_name(), target),
+    )
+}
+
+// Conversion out of Value fails when the variant does not match the Rust type.
+// For Option<T>, NULL becomes None
+macro_rules! impl_try_from_value {
+    ($($variant:ident($t:ty)),*) => {
+        $(
+            impl TryFrom<Value> for $t {
+                type Error = GridDbError;
+
+                fn try_from(item: Value) -> Result<$t, GridDbError> {
+                    match item {
+                        Value::$variant(val) => Ok(val),
+                        _ => Err(convert_error(&item, stringify!($t))),
+                    }
+                }
+            }
+
+            impl TryFrom<&Value> for $t {
+                type Error = GridDbError;
+
+                fn try_from(item: &Value) -> Result<$t, GridDbError> {
+                    match item {
+                        Value::$variant(val) => Ok(val.clone()),
+                        _ => Err(convert_error(item, stringify!($t))),
+                    }
+                }
+            }
+
+            impl TryFrom<Value> for Option<$t> {
+                type Error = GridDbError;
+
+                fn try_from(item: Value) -> Result<Option<$t>, GridDbError> {
+                    match item {
+                        Value::Null => Ok(None),
+                        val => <$t>::try_from(val).map(Some),
+                    }
+                }
+            }
+
+            impl TryFrom<&Value> for Option<$t> {
+                type Error = GridDbError;
+
+                fn try_from(item: &Value) -> Result<Option<$t>, GridDbError> {
+                    match item {
+                        Value::Null => Ok(None),
+                        val => <$t>::try_from(val).map(Some),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_value!(
+    Str(String),
+    Bool(bool),
+    Byte(i8),
+    Short(i16),
+    Integer(i32),
+    Long(i64),
+    Float(f32),
+    Double(f64),
+    Timestamp(Timestamp),
+    Geometry(Geometry),
+    Blob(Vec<u8>),
+    StringArray(Vec<String>),
+    BoolArray(Vec<bool>),
+    ByteArray(Vec<i8>),
+    ShortArray(Vec<i16>),
+    IntegerArray(Vec<i32>),
+    LongArray(Vec<i64>),
+    FloatArray(Vec<f32>),
+    DoubleArray(Vec<f64>),
+    TimestampArray(Vec<Timestamp>)
+);
+
 pub trait FieldBinder {
     fn bind(&self, column: i32, row: *mut GSRow) -> i32;
 }
@@ -260,6 +440,7 @@ pub trait FieldBinder {
 impl FieldBinder for Value {
     fn bind(&self, column: i32, row: *mut GSRow) -> i32 {
         match self {
+            Value::Null => unsafe { ffi::gsSetRowFieldNull(row, column) },
             Value::Str(a) => {
                 let key_tmp = CString::new((*a).clone()).unwrap();
                 let key_ptr = key_tmp.as_ptr();
@@ -273,9 +454,10 @@ impl FieldBinder for Value {
             Value::Float(a) => unsafe { ffi::gsSetRowFieldByFloat(row, column, *a) },
             Value::Double(a) => unsafe { ffi::gsSetRowFieldByDouble(row, column, *a) },
             Value::Timestamp(a) => unsafe { ffi::gsSetRowFieldByTimestamp(row, column, a.value) },
-            Value::Geometry(a) => unsafe {
-                ffi::gsSetRowFieldByGeometry(row, column, a.value.as_ptr() as *const i8)
-            },
+            Value::Geometry(a) => {
+                let wkt_tmp = CString::new(a.to_wkt()).unwrap();
+                unsafe { ffi::gsSetRowFieldByGeometry(row, column, wkt_tmp.as_ptr()) }
+            }
             Value::Blob(a) => {
                 let vec_blob: Vec<ffi::GSBlob> = vec![ffi::GSBlob {
                     size: a.len() as u64,
@@ -283,6 +465,127 @@ impl FieldBinder for Value {
                 }];
                 unsafe { ffi::gsSetRowFieldByBlob(row, column, vec_blob.as_ptr()) }
             }
+            Value::StringArray(a) => {
+                let c_strings: Vec<CString> = a
+                    .iter()
+                    .map(|s| CString::new(s.as_str()).unwrap())
+                    .collect();
+                let ptrs: Vec<*const ffi::GSChar> = c_strings.iter().map(|s| s.as_ptr()).collect();
+                unsafe {
+                    ffi::gsSetRowFieldByStringArray(row, column, ptrs.as_ptr(), ptrs.len() as u64)
+                }
+            }
+            Value::BoolArray(a) => {
+                let gs_bools: Vec<ffi::GSBool> = a.iter().map(|b| *b as i8).collect();
+                unsafe {
+                    ffi::gsSetRowFieldByBoolArray(row, column, gs_bools.as_ptr(), a.len() as u64)
+                }
+            }
+            Value::ByteArray(a) => unsafe {
+                ffi::gsSetRowFieldByByteArray(row, column, a.as_ptr(), a.len() as u64)
+            },
+            Value::ShortArray(a) => unsafe {
+                ffi::gsSetRowFieldByShortArray(row, column, a.as_ptr(), a.len() as u64)
+            },
+            Value::IntegerArray(a) => unsafe {
+                ffi::gsSetRowFieldByIntegerArray(row, column, a.as_ptr(), a.len() as u64)
+            },
+            Value::LongArray(a) => unsafe {
+                ffi::gsSetRowFieldByLongArray(row, column, a.as_ptr(), a.len() as u64)
+            },
+            Value::FloatArray(a) => unsafe {
+                ffi::gsSetRowFieldByFloatArray(row, column, a.as_ptr(), a.len() as u64)
+            },
+            Value::DoubleArray(a) => unsafe {
+                ffi::gsSetRowFieldByDoubleArray(row, column, a.as_ptr(), a.len() as u64)
+            },
+            Value::TimestampArray(a) => {
+                let timestamps: Vec<ffi::GSTimestamp> = a.iter().map(|t| t.value).collect();
+                unsafe {
+                    ffi::gsSetRowFieldByTimestampArray(
+                        row,
+                        column,
+                        timestamps.as_ptr(),
+                        a.len() as u64,
+                    )
+                }
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_from_matching_type() {
+        assert_eq!(i64::try_from(Value::Long(100)).unwrap(), 100);
+        assert_eq!(
+            String::try_from(Value::Str("name01".to_string())).unwrap(),
+            "name01"
+        );
+        assert_eq!(
+            Vec::<u8>::try_from(Value::Blob(vec![65, 66])).unwrap(),
+            vec![65, 66]
+        );
+        let timestamp = Timestamp::try_from(Value::Timestamp(Timestamp { value: 1000 })).unwrap();
+        assert_eq!(timestamp.value, 1000);
+    }
+
+    #[test]
+    fn try_from_mismatched_type() {
+        let error = i64::try_from(Value::Integer(100)).unwrap_err();
+        assert_eq!(error.code, ERROR_CONVERT_DATA);
+        assert_eq!(error.message(), "Cannot convert Integer value to i64");
+        let error = bool::try_from(Value::Str("true".to_string())).unwrap_err();
+        assert_eq!(error.message(), "Cannot convert String value to bool");
+    }
+
+    #[test]
+    fn try_from_null() {
+        let error = i64::try_from(Value::Null).unwrap_err();
+        assert_eq!(error.code, ERROR_CONVERT_DATA);
+        assert_eq!(error.message(), "Cannot convert Null value to i64");
+        assert_eq!(Option::<i64>::try_from(Value::Null).unwrap(), None);
+        assert_eq!(Option::<i64>::try_from(Value::Long(7)).unwrap(), Some(7));
+        assert!(Option::<i64>::try_from(Value::Bool(true)).is_err());
+    }
+
+    #[test]
+    fn try_from_borrowed_value() {
+        let value = Value::Str("name01".to_string());
+        assert_eq!(String::try_from(&value).unwrap(), "name01");
+        assert!(i32::try_from(&value).is_err());
+        assert_eq!(
+            Option::<String>::try_from(&value).unwrap().as_deref(),
+            Some("name01")
+        );
+        assert_eq!(Option::<String>::try_from(&Value::Null).unwrap(), None);
+        // value is still usable
+        assert_eq!(value.as_str(), Some("name01"));
+    }
+
+    #[test]
+    fn try_get_value_macro() {
+        let row = [Value::Bool(true), Value::Null];
+        let active: Result<bool, GridDbError> = crate::try_get_value![row[0]];
+        assert!(active.unwrap());
+        let count: Result<i64, GridDbError> = crate::try_get_value![row[0]];
+        assert!(count.is_err());
+        let comment: Result<Option<String>, GridDbError> = crate::try_get_value![row[1]];
+        assert_eq!(comment.unwrap(), None);
+    }
+
+    #[test]
+    fn accessors() {
+        assert_eq!(Value::Long(5).as_i64(), Some(5));
+        assert_eq!(Value::Integer(5).as_i64(), None);
+        assert_eq!(Value::Bool(false).as_bool(), Some(false));
+        assert_eq!(Value::Blob(vec![1, 2]).as_blob(), Some(&[1u8, 2][..]));
+        assert_eq!(Value::Null.as_str(), None);
+        assert!(Value::Null.is_null());
+        assert_eq!(Value::Null.data_type(), None);
+        assert_eq!(Value::Double(1.5).data_type(), Some(Type::Double));
+    }
+}
@@ -16,14 +16,30 @@
 
 #![allow(non_snake_case)]
 pub mod AggregationResult;
+pub mod Collection;
 pub mod Const;
 pub mod Container;
 pub mod ContainerInfo;
+pub mod Error;
+pub mod Geometry;
+pub mod GridRow;
+pub mod IndexInfo;
+pub mod PartitionController;
 pub mod Query;
+pub mod QueryAnalysisEntry;
+pub mod RetryPolicy;
+pub mod Row;
+pub mod RowKeyPredicate;
 pub mod RowSet;
+#[cfg(feature = "serde")]
+pub mod serde;
 pub mod Store;
 pub mod StoreFactory;
+pub mod TimeSeries;
+pub mod TimeSeriesProperties;
+pub mod TriggerInfo;
 pub mod Type;
+pub mod TypedContainer;
 pub mod Util;
 pub mod Value;
 
@@ -43,9 +59,22 @@ macro_rules! gsvec {
 }
 
 #[macro_export]
-// Support get data from Value
+// Support get data from Value, panic when the type does not match or the
+// value is NULL. Kept for old code, use try_get_value! instead
+#[deprecated(note = "panics on a type mismatch or NULL, use try_get_value! instead")]
 macro_rules! get_value {
     ($input:expr) => {
-        $input.clone().into()
+        match ::std::convert::TryInto::try_into(&$input) {
+            Ok(val) => val,
+            Err(error) => panic!("{}", error),
+        }
+    };
+}
+
+#[macro_export]
+// Support get data from Value as Result<T, GridDbError>
+macro_rules! try_get_value {
+    ($input:expr) => {
+        ::std::convert::TryInto::try_into(&$input)
     };
 }
@@ -0,0 +1,1074 @@
+/*
+    Copyright (c) 2022 TOSHIBA Digital Solutions Corporation.
+
+    Licensed under the Apache License, Version 2.0 (the "License");
+    you may not use this file except in compliance with the License.
+    You may obtain a copy of the License at
+
+        http://www.apache.org/licenses/LICENSE-2.0
+
+    Unless required by applicable law or agreed to in writing, software
+    distributed under the License is distributed on an "AS IS" BASIS,
+    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+    See the License for the specific language governing permissions and
+    limitations under the License.
+*/
+
+// Conversion between rows and any Serialize / Deserialize type.
+// Fields are matched to columns by name, ignoring ASCII case like GridDB does.
+
+use crate::griddb::Const::*;
+use crate::griddb::Container::Container;
+use crate::griddb::Error::*;
+use crate::griddb::RowSet::RowSet;
+use crate::griddb::Type::*;
+use crate::griddb::Value::{Geometry, Timestamp, Value};
+use ::serde::de::value::{SeqDeserializer, StringDeserializer};
+use ::serde::de::{self, DeserializeOwned, IntoDeserializer, MapAccess, Visitor};
+use ::serde::ser::{self, Impossible, Serialize};
+use ::serde::{forward_to_deserialize_any, Deserialize, Deserializer, Serializer};
+use chrono::{DateTime, SecondsFormat, TimeZone, Utc};
+use std::collections::HashMap;
+use std::fmt;
+
+impl ser::Error for GridDbError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        GridDbError::new(ERROR_CONVERT_DATA, &msg.to_string())
+    }
+}
+
+impl de::Error for GridDbError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        GridDbError::new(ERROR_CONVERT_DATA, &msg.to_string())
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        GridDbError::new(
+            ERROR_CONVERT_DATA,
+            &format!("Column {} is missing in the row", field),
+        )
+    }
+}
+
+// Serialize value into a row of container
+pub fn to_row<T>(value: &T, container: &Container) -> Result<Vec<Value>, GridDbError>
+where
+    T: Serialize + ?Sized,
+{
+    to_row_with_columns(value, &container.column_info_list())
+}
+
+// Serialize value into a row with the given columns. A missing field is
+// stored as NULL when the column is nullable
+pub fn to_row_with_columns<T>(
+    value: &T,
+    columns: &[(String, Type, TypeOption)],
+) -> Result<Vec<Value>, GridDbError>
+where
+    T: Serialize + ?Sized,
+{
+    let mut fields = HashMap::new();
+    for (name, field) in value.serialize(RowSerializer)? {
+        if !columns
+            .iter()
+            .any(|column| column.0.eq_ignore_ascii_case(&name))
+        {
+            return Err(GridDbError::new(
+                ERROR_CONVERT_DATA,
+                &format!("Field {} does not match any column", name),
+            ));
+        }
+        fields.insert(name.to_ascii_lowercase(), field);
+    }
+    columns
+        .iter()
+        .map(
+            |(name, column_type, option)| match fields.remove(&name.to_ascii_lowercase()) {
+                Some(Field::Null) | None if *option == TypeOption::Nullable => Ok(Value::Null),
+                Some(Field::Null) => Err(GridDbError::new(
+                    ERROR_CONVERT_DATA,
+                    &format!("Column {} is NOT NULL but the value is null", name),
+                )),
+                Some(field) => field_to_value(field, *column_type).map_err(|message| {
+                    GridDbError::new(ERROR_CONVERT_DATA, &format!("Column {}: {}", name, message))
+                }),
+                None => Err(GridDbError::new(
+                    ERROR_CONVERT_DATA,
+                    &format!("Column {} is missing in the serialized value", name),
+                )),
+            },
+        )
+        .collect()
+}
+
+// Deserialize a row returned by Container::get or RowSet::next
+pub fn from_row<T>(row: Vec<Value>, column_names: &[String]) -> Result<T, GridDbError>
+where
+    T: DeserializeOwned,
+{
+    if row.len() != column_names.len() {
+        return Err(GridDbError::new(
+            ERROR_CONVERT_DATA,
+            &format!(
+                "Row has {} values but {} column names are given",
+                row.len(),
+                column_names.len()
+            ),
+        ));
+    }
+    T::deserialize(RowDeserializer {
+        row: column_names.iter().cloned().zip(row).collect(),
+    })
+}
+
+// Deserialize the next row of row_set
+pub fn next_row<T>(row_set: &RowSet) -> Result<T, GridDbError>
+where
+    T: DeserializeOwned,
+{
+    from_row(row_set.next_values()?, row_set.column_names())
+}
+
+// TIMESTAMP is serialized as milliseconds since the epoch, and can be
+// deserialized from milliseconds or an RFC 3339 string
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.value)
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct TimestampVisitor;
+
+        impl<'de> Visitor<'de> for TimestampVisitor {
+            type Value = Timestamp;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("milliseconds since the epoch or an RFC 3339 string")
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Timestamp, E> {
+                Ok(Timestamp { value })
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Timestamp, E> {
+                i64::try_from(value)
+                    .map(|value| Timestamp { value })
+                    .map_err(|_| E::custom(format!("timestamp {} is out of range", value)))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Timestamp, E> {
+                parse_timestamp(value).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_i64(TimestampVisitor)
+    }
+}
+
+// GEOMETRY is serialized as WKT
+impl Serialize for Geometry {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Geometry {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct GeometryVisitor;
+
+        impl<'de> Visitor<'de> for GeometryVisitor {
+            type Value = Geometry;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a WKT string")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Geometry, E> {
+                value
+                    .parse::<Geometry>()
+                    .map_err(|error| E::custom(error.message()))
+            }
+        }
+
+        deserializer.deserialize_str(GeometryVisitor)
+    }
+}
+
+fn parse_timestamp(value: &str) -> Result<Timestamp, String> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|time| Timestamp {
+            value: time.timestamp_millis(),
+        })
+        .map_err(|error| format!("invalid timestamp {:?}: {}", value, error))
+}
+
+fn format_timestamp(value: i64) -> Option<String> {
+    Utc.timestamp_millis_opt(value)
+        .single()
+        .map(|time| time.to_rfc3339_opts(SecondsFormat::Millis, true))
+}
+
+// Field value produced by serialization, before it is bound to a column type
+#[derive(Debug)]
+enum Field {
+    Null,
+    Bool(bool),
+    Int(i128),
+    Float(f64),
+    Str(String),
+    Bytes(Vec<u8>),
+    Seq(Vec<Field>),
+}
+
+impl Field {
+    fn describe(&self) -> &'static str {
+        match self {
+            Field::Null => "null",
+            Field::Bool(_) => "a bool",
+            Field::Int(_) => "an integer",
+            Field::Float(_) => "a float",
+            Field::Str(_) => "a string",
+            Field::Bytes(_) => "bytes",
+            Field::Seq(_) => "a sequence",
+        }
+    }
+}
+
+fn field_to_value(field: Field, column_type: Type) -> Result<Value, String> {
+    match (column_type, field) {
+        (Type::String, Field::Str(val)) => Ok(Value::Str(val)),
+        (Type::Bool, Field::Bool(val)) => Ok(Value::Bool(val)),
+        (Type::Byte, Field::Int(val)) => int_value(val, column_type).map(Value::Byte),
+        (Type::Short, Field::Int(val)) => int_value(val, column_type).map(Value::Short),
+        (Type::Integer, Field::Int(val)) => int_value(val, column_type).map(Value::Integer),
+        (Type::Long, Field::Int(val)) => int_value(val, column_type).map(Value::Long),
+        (Type::Float, Field::Float(val)) => Ok(Value::Float(val as f32)),
+        (Type::Double, Field::Float(val)) => Ok(Value::Double(val)),
+        (Type::Timestamp, Field::Int(val)) => {
+            int_value(val, column_type).map(|value| Value::Timestamp(Timestamp { value }))
+        }
+        (Type::Timestamp, Field::Str(val)) => parse_timestamp(&val).map(Value::Timestamp),
+        (Type::Geometry, Field::Str(val)) => val
+            .parse::<Geometry>()
+            .map(Value::Geometry)
+            .map_err(|error| error.message().to_string()),
+        (Type::Blob, Field::Bytes(val)) => Ok(Value::Blob(val)),
+        (Type::Blob, Field::Seq(items)) => items
+            .into_iter()
+            .map(|item| match item {
+                Field::Int(val) => int_value(val, column_type),
+                item => Err(format!("cannot store {} in a BLOB", item.describe())),
+            })
+            .collect::<Result<Vec<u8>, String>>()
+            .map(Value::Blob),
+        (Type::StringArray, Field::Seq(items)) => {
+            array_value(items, Type::String).map(Value::StringArray)
+        }
+        (Type::BoolArray, Field::Seq(items)) => {
+            array_value(items, Type::Bool).map(Value::BoolArray)
+        }
+        (Type::ByteArray, Field::Seq(items)) => {
+            array_value(items, Type::Byte).map(Value::ByteArray)
+        }
+        (Type::ShortArray, Field::Seq(items)) => {
+            array_value(items, Type::Short).map(Value::ShortArray)
+        }
+        (Type::IntegerArray, Field::Seq(items)) => {
+            array_value(items, Type::Integer).map(Value::IntegerArray)
+        }
+        (Type::LongArray, Field::Seq(items)) => {
+            array_value(items, Type::Long).map(Value::LongArray)
+        }
+        (Type::FloatArray, Field::Seq(items)) => {
+            array_value(items, Type::Float).map(Value::FloatArray)
+        }
+        (Type::DoubleArray, Field::Seq(items)) => {
+            array_value(items, Type::Double).map(Value::DoubleArray)
+        }
+        (Type::TimestampArray, Field::Seq(items)) => {
+            array_value(items, Type::Timestamp).map(Value::TimestampArray)
+        }
+        (column_type, field) => Err(format!(
+            "cannot store {} in {:?} column",
+            field.describe(),
+            column_type
+        )),
+    }
+}
+
+fn int_value<T: TryFrom<i128>>(val: i128, column_type: Type) -> Result<T, String> {
+    T::try_from(val).map_err(|_| format!("{} is out of range for {:?} column", val, column_type))
+}
+
+fn array_value<T>(items: Vec<Field>, element_type: Type) -> Result<Vec<T>, String>
+where
+    T: TryFrom<Value, Error = GridDbError>,
+{
+    items
+        .into_iter()
+        .map(|item| {
+            let value = field_to_value(item, element_type)?;
+            T::try_from(value).map_err(|error| error.message().to_string())
+        })
+        .collect()
+}
+
+fn row_shape_error() -> GridDbError {
+    GridDbError::new(
+        ERROR_CONVERT_DATA,
+        "A row can only be serialized from a struct or a map",
+    )
+}
+
+fn field_shape_error(kind: &str) -> GridDbError {
+    GridDbError::new(
+        ERROR_CONVERT_DATA,
+        &format!("Cannot serialize {} as a column value", kind),
+    )
+}
+
+// Serializer for the whole row: a struct or a map of column name to value
+struct RowSerializer;
+
+struct RowFields {
+    fields: Vec<(String, Field)>,
+    key: Option<String>,
+}
+
+macro_rules! reject_row {
+    ($($method:ident($($arg:ty),*)),*) => {
+        $(
+            fn $method(self, $(_: $arg),*) -> Result<Self::Ok, GridDbError> {
+                Err(row_shape_error())
+            }
+        )*
+    };
+}
+
+impl Serializer for RowSerializer {
+    type Ok = Vec<(String, Field)>;
+    type Error = GridDbError;
+    type SerializeSeq = Impossible<Self::Ok, GridDbError>;
+    type SerializeTuple = Impossible<Self::Ok, GridDbError>;
+    type SerializeTupleStruct = Impossible<Self::Ok, GridDbError>;
+    type SerializeTupleVariant = Impossible<Self::Ok, GridDbError>;
+    type SerializeMap = RowFields;
+    type SerializeStruct = RowFields;
+    type SerializeStructVariant = Impossible<Self::Ok, GridDbError>;
+
+    reject_row!(
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+        serialize_none(),
+        serialize_unit(),
+        serialize_unit_struct(&'static str),
+        serialize_unit_variant(&'static str, u32, &'static str)
+    );
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Self::Ok, GridDbError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, GridDbError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, GridDbError> {
+        Err(row_shape_error())
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, GridDbError> {
+        Err(row_shape_error())
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, GridDbError> {
+        Err(row_shape_error())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, GridDbError> {
+        Err(row_shape_error())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, GridDbError> {
+        Err(row_shape_error())
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, GridDbError> {
+        Ok(RowFields {
+            fields: Vec::with_capacity(len.unwrap_or(0)),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, GridDbError> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, GridDbError> {
+        Err(row_shape_error())
+    }
+}
+
+impl ser::SerializeStruct for RowFields {
+    type Ok = Vec<(String, Field)>;
+    type Error = GridDbError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), GridDbError> {
+        let field = value.serialize(FieldSerializer)?;
+        self.fields.push((key.to_string(), field));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, GridDbError> {
+        Ok(self.fields)
+    }
+}
+
+impl ser::SerializeMap for RowFields {
+    type Ok = Vec<(String, Field)>;
+    type Error = GridDbError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), GridDbError> {
+        match key.serialize(FieldSerializer)? {
+            Field::Str(name) => {
+                self.key = Some(name);
+                Ok(())
+            }
+            field => Err(GridDbError::new(
+                ERROR_CONVERT_DATA,
+                &format!("Column name must be a string, not {}", field.describe()),
+            )),
+        }
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), GridDbError> {
+        let key = self.key.take().ok_or_else(row_shape_error)?;
+        let field = value.serialize(FieldSerializer)?;
+        self.fields.push((key, field));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, GridDbError> {
+        Ok(self.fields)
+    }
+}
+
+// Serializer for one column value
+struct FieldSerializer;
+
+struct SeqFields {
+    items: Vec<Field>,
+}
+
+impl Serializer for FieldSerializer {
+    type Ok = Field;
+    type Error = GridDbError;
+    type SerializeSeq = SeqFields;
+    type SerializeTuple = SeqFields;
+    type SerializeTupleStruct = SeqFields;
+    type SerializeTupleVariant = Impossible<Field, GridDbError>;
+    type SerializeMap = Impossible<Field, GridDbError>;
+    type SerializeStruct = Impossible<Field, GridDbError>;
+    type SerializeStructVariant = Impossible<Field, GridDbError>;
+
+    fn serialize_bool(self, v: bool) -> Result<Field, GridDbError> {
+        Ok(Field::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Field, GridDbError> {
+        Ok(Field::Int(v.into()))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Field, GridDbError> {
+        Ok(Field::Int(v.into()))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Field, GridDbError> {
+        Ok(Field::Int(v.into()))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Field, GridDbError> {
+        Ok(Field::Int(v.into()))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Field, GridDbError> {
+        Ok(Field::Int(v.into()))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Field, GridDbError> {
+        Ok(Field::Int(v.into()))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Field, GridDbError> {
+        Ok(Field::Int(v.into()))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Field, GridDbError> {
+        Ok(Field::Int(v.into()))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Field, GridDbError> {
+        Ok(Field::Float(v.into()))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Field, GridDbError> {
+        Ok(Field::Float(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Field, GridDbError> {
+        Ok(Field::Str(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Field, GridDbError> {
+        Ok(Field::Str(v.to_string()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Field, GridDbError> {
+        Ok(Field::Bytes(v.to_vec()))
+    }
+
+    fn serialize_none(self) -> Result<Field, GridDbError> {
+        Ok(Field::Null)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Field, GridDbError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Field, GridDbError> {
+        Ok(Field::Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Field, GridDbError> {
+        Ok(Field::Null)
+    }
+
+    // Unit enum variants are stored by name
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Field, GridDbError> {
+        Ok(Field::Str(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Field, GridDbError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Field, GridDbError> {
+        Err(field_shape_error("an enum variant with data"))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SeqFields, GridDbError> {
+        Ok(SeqFields {
+            items: Vec::with_capacity(len.unwrap_or(0)),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SeqFields, GridDbError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SeqFields, GridDbError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, GridDbError> {
+        Err(field_shape_error("an enum variant with data"))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, GridDbError> {
+        Err(field_shape_error("a map"))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, GridDbError> {
+        Err(field_shape_error("a nested struct"))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, GridDbError> {
+        Err(field_shape_error("an enum variant with data"))
+    }
+}
+
+impl ser::SerializeSeq for SeqFields {
+    type Ok = Field;
+    type Error = GridDbError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), GridDbError> {
+        self.items.push(value.serialize(FieldSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Field, GridDbError> {
+        Ok(Field::Seq(self.items))
+    }
+}
+
+impl ser::SerializeTuple for SeqFields {
+    type Ok = Field;
+    type Error = GridDbError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), GridDbError> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Field, GridDbError> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqFields {
+    type Ok = Field;
+    type Error = GridDbError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), GridDbError> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Field, GridDbError> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+// Deserializer for the whole row, seen as a map of column name to value
+struct RowDeserializer {
+    row: Vec<(String, Value)>,
+}
+
+impl<'de> Deserializer<'de> for RowDeserializer {
+    type Error = GridDbError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, GridDbError> {
+        visitor.visit_map(RowAccess {
+            iter: self.row.into_iter(),
+            current: None,
+        })
+    }
+
+    // Use the spelling of the struct field for a column differing only in case
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, GridDbError> {
+        let row = self
+            .row
+            .into_iter()
+            .map(|(name, value)| {
+                match fields
+                    .iter()
+                    .find(|field| field.eq_ignore_ascii_case(&name))
+                {
+                    Some(field) => (field.to_string(), value),
+                    None => (name, value),
+                }
+            })
+            .collect();
+        RowDeserializer { row }.deserialize_any(visitor)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map enum identifier ignored_any
+    }
+}
+
+struct RowAccess {
+    iter: std::vec::IntoIter<(String, Value)>,
+    current: Option<(String, Value)>,
+}
+
+impl<'de> MapAccess<'de> for RowAccess {
+    type Error = GridDbError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, GridDbError>
+    where
+        K: de::DeserializeSeed<'de>,
+    {
+        match self.iter.next() {
+            Some((name, value)) => {
+                let key: StringDeserializer<GridDbError> = name.clone().into_deserializer();
+                self.current = Some((name, value));
+                seed.deserialize(key).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, GridDbError>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        let (name, value) = self.current.take().ok_or_else(|| {
+            GridDbError::new(ERROR_CONVERT_DATA, "Value requested before column name")
+        })?;
+        seed.deserialize(ValueDeserializer(value)).map_err(|error| {
+            GridDbError::new(
+                ERROR_CONVERT_DATA,
+                &format!("Column {}: {}", name, error.message()),
+            )
+        })
+    }
+}
+
+// Deserializer for one column value
+pub struct ValueDeserializer(Value);
+
+impl<'de> IntoDeserializer<'de, GridDbError> for Value {
+    type Deserializer = ValueDeserializer;
+
+    fn into_deserializer(self) -> ValueDeserializer {
+        ValueDeserializer(self)
+    }
+}
+
+fn visit_array<'de, V, T>(visitor: V, items: Vec<T>) -> Result<V::Value, GridDbError>
+where
+    V: Visitor<'de>,
+    T: Into<Value>,
+{
+    let mut seq = SeqDeserializer::new(items.into_iter().map(Into::<Value>::into));
+    let result = visitor.visit_seq(&mut seq)?;
+    seq.end()?;
+    Ok(result)
+}
+
+impl<'de> Deserializer<'de> for ValueDeserializer {
+    type Error = GridDbError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, GridDbError> {
+        match self.0 {
+            Value::Null => visitor.visit_unit(),
+            Value::Str(val) => visitor.visit_string(val),
+            Value::Bool(val) => visitor.visit_bool(val),
+            Value::Byte(val) => visitor.visit_i8(val),
+            Value::Short(val) => visitor.visit_i16(val),
+            Value::Integer(val) => visitor.visit_i32(val),
+            Value::Long(val) => visitor.visit_i64(val),
+            Value::Float(val) => visitor.visit_f32(val),
+            Value::Double(val) => visitor.visit_f64(val),
+            Value::Timestamp(val) => visitor.visit_i64(val.value),
+            Value::Geometry(val) => visitor.visit_string(val.to_wkt()),
+            Value::Blob(val) => visitor.visit_byte_buf(val),
+            Value::StringArray(val) => visit_array(visitor, val),
+            Value::BoolArray(val) => visit_array(visitor, val),
+            Value::ByteArray(val) => visit_array(visitor, val),
+            Value::ShortArray(val) => visit_array(visitor, val),
+            Value::IntegerArray(val) => visit_array(visitor, val),
+            Value::LongArray(val) => visit_array(visitor, val),
+            Value::FloatArray(val) => visit_array(visitor, val),
+            Value::DoubleArray(val) => visit_array(visitor, val),
+            Value::TimestampArray(val) => visit_array(visitor, val),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, GridDbError> {
+        match self.0 {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    // A TIMESTAMP read as a string is formatted as RFC 3339
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, GridDbError> {
+        match self.0 {
+            Value::Timestamp(val) => match format_timestamp(val.value) {
+                Some(text) => visitor.visit_string(text),
+                None => visitor.visit_i64(val.value),
+            },
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, GridDbError> {
+        self.deserialize_str(visitor)
+    }
+
+    // Vec<u8> is read from a BLOB as a sequence
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, GridDbError> {
+        match self.0 {
+            Value::Blob(val) => {
+                let mut seq = SeqDeserializer::new(val.into_iter());
+                let result = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(result)
+            }
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, GridDbError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    // Unit enum variants are read by name
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, GridDbError> {
+        match self.0 {
+            Value::Str(val) => visitor.visit_enum(val.into_deserializer()),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char bytes
+        byte_buf unit unit_struct tuple tuple_struct map struct identifier
+        ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+    struct Person {
+        name: String,
+        count: i64,
+        comment: Option<String>,
+    }
+
+    #[derive(::serde::Serialize)]
+    struct NameOnly {
+        name: String,
+    }
+
+    #[derive(::serde::Serialize)]
+    struct PersonWithAge {
+        name: String,
+        count: i64,
+        age: i32,
+    }
+
+    #[derive(::serde::Serialize)]
+    struct CountAsText {
+        name: String,
+        count: String,
+    }
+
+    fn columns() -> Vec<(String, Type, TypeOption)> {
+        vec![
+            ("NAME".to_string(), Type::String, TypeOption::NotNull),
+            ("Count".to_string(), Type::Long, TypeOption::NotNull),
+            ("comment".to_string(), Type::String, TypeOption::Nullable),
+        ]
+    }
+
+    fn column_names() -> Vec<String> {
+        columns().into_iter().map(|column| column.0).collect()
+    }
+
+    #[test]
+    fn to_row_matches_columns_ignoring_case() {
+        let person = Person {
+            name: "name01".to_string(),
+            count: 100,
+            comment: Some("first".to_string()),
+        };
+        let row = to_row_with_columns(&person, &columns()).unwrap();
+        assert!(matches!(
+            row.as_slice(),
+            [Value::Str(name), Value::Long(100), Value::Str(comment)]
+                if name == "name01" && comment == "first"
+        ));
+    }
+
+    #[test]
+    fn to_row_null_and_missing_fields() {
+        let person = Person {
+            name: "name01".to_string(),
+            count: 100,
+            comment: None,
+        };
+        let row = to_row_with_columns(&person, &columns()).unwrap();
+        assert!(matches!(row[2], Value::Null));
+
+        // A missing field is NULL in a nullable column, an error otherwise
+        let columns = vec![
+            ("name".to_string(), Type::String, TypeOption::NotNull),
+            ("comment".to_string(), Type::String, TypeOption::Nullable),
+        ];
+        let name = NameOnly {
+            name: "name01".to_string(),
+        };
+        let row = to_row_with_columns(&name, &columns).unwrap();
+        assert!(matches!(row[1], Value::Null));
+        let error = to_row_with_columns(&name, &self::columns()).unwrap_err();
+        assert_eq!(error.code, ERROR_CONVERT_DATA);
+        assert!(error.message().contains("Count"));
+    }
+
+    #[test]
+    fn to_row_rejects_unknown_field() {
+        let person = PersonWithAge {
+            name: "name01".to_string(),
+            count: 100,
+            age: 30,
+        };
+        let error = to_row_with_columns(&person, &columns()).unwrap_err();
+        assert_eq!(error.code, ERROR_CONVERT_DATA);
+        assert!(error.message().contains("age"));
+    }
+
+    #[test]
+    fn to_row_rejects_type_mismatch() {
+        let person = CountAsText {
+            name: "name01".to_string(),
+            count: "many".to_string(),
+        };
+        let error = to_row_with_columns(&person, &columns()).unwrap_err();
+        assert_eq!(error.code, ERROR_CONVERT_DATA);
+        assert!(error.message().contains("Count"));
+    }
+
+    #[test]
+    fn from_row_matches_columns_ignoring_case() {
+        let row = vec![
+            Value::Str("name01".to_string()),
+            Value::Long(100),
+            Value::Null,
+        ];
+        let person: Person = from_row(row, &column_names()).unwrap();
+        assert_eq!(
+            person,
+            Person {
+                name: "name01".to_string(),
+                count: 100,
+                comment: None,
+            }
+        );
+    }
+
+    #[test]
+    fn from_row_errors() {
+        // Value of the wrong type
+        let row = vec![
+            Value::Str("name01".to_string()),
+            Value::Str("many".to_string()),
+            Value::Null,
+        ];
+        let error = from_row::<Person>(row, &column_names()).unwrap_err();
+        assert_eq!(error.code, ERROR_CONVERT_DATA);
+        // The column is reported with the spelling of the field
+        assert!(error.message().contains("Column count"));
+
+        // Column of a field that is not an Option is missing
+        let row = vec![Value::Str("name01".to_string()), Value::Null];
+        let names = vec!["name".to_string(), "comment".to_string()];
+        let error = from_row::<Person>(row, &names).unwrap_err();
+        assert!(error.message().contains("count"));
+
+        // Row and names of different length
+        let row = vec![Value::Str("name01".to_string())];
+        assert!(from_row::<Person>(row, &column_names()).is_err());
+    }
+
+    #[test]
+    fn timestamp_from_millis_or_rfc3339() {
+        let mut fields = HashMap::new();
+        fields.insert("time", "2022-01-02T03:04:05.678Z");
+        let columns = vec![("time".to_string(), Type::Timestamp, TypeOption::NotNull)];
+        let row = to_row_with_columns(&fields, &columns).unwrap();
+        assert!(matches!(
+            row.as_slice(),
+            [Value::Timestamp(Timestamp {
+                value: 1641092645678
+            })]
+        ));
+        let names = vec!["time".to_string()];
+        let time: HashMap<String, String> = from_row(row.clone(), &names).unwrap();
+        assert_eq!(time["time"], "2022-01-02T03:04:05.678Z");
+        let time: HashMap<String, Timestamp> = from_row(row, &names).unwrap();
+        assert_eq!(time["time"].value, 1641092645678);
+    }
+}
@@ -14,4 +14,7 @@
     limitations under the License.
 */
 
+// Lets #[derive(GridRow)] refer to ::griddb_rust inside this crate too
+extern crate self as griddb_rust;
+
 pub mod griddb;